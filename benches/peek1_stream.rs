@@ -0,0 +1,110 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ergo_pin::ergo_pin;
+use futures_util::stream::{self, StreamExt as _};
+use percolate::{
+	predicate::ops::lt,
+	stream::{Peek1Stream, PeekStream},
+};
+use pollster::block_on;
+
+const LEN: u32 = 1000;
+
+#[ergo_pin]
+async fn peek_next_peekable() -> u32 {
+	let mut stream = pin!(stream::iter(0..LEN).fuse().peekable());
+	let mut sum = 0;
+	while let Some(&item) = stream.as_mut().peek().await {
+		sum += item;
+		stream.next().await;
+	}
+	sum
+}
+
+#[ergo_pin]
+async fn peek_next_peek_stream() -> u32 {
+	let mut stream = pin!(PeekStream::<_, 1>::new(stream::iter(0..LEN).fuse()));
+	let mut sum = 0;
+	while let Some(&item) = stream.as_mut().peek_1().await {
+		sum += item;
+		stream.next().await;
+	}
+	sum
+}
+
+#[ergo_pin]
+async fn peek_next_peek1_stream() -> u32 {
+	let mut stream = pin!(Peek1Stream::new(stream::iter(0..LEN).fuse()));
+	let mut sum = 0;
+	while let Some(&item) = stream.as_mut().peek().await {
+		sum += item;
+		stream.next().await;
+	}
+	sum
+}
+
+#[ergo_pin]
+async fn next_if_peekable() -> usize {
+	let mut stream = pin!(stream::iter(0..LEN).fuse().peekable());
+	let mut count = 0;
+	while stream
+		.as_mut()
+		.next_if(|item| *item < LEN / 2)
+		.await
+		.is_some()
+	{
+		count += 1;
+	}
+	count
+}
+
+#[ergo_pin]
+async fn next_if_peek_stream() -> usize {
+	let mut stream = pin!(PeekStream::<_, 1>::new(stream::iter(0..LEN).fuse()));
+	let mut count = 0;
+	while stream.as_mut().next_if(lt(LEN / 2)).await.is_some() {
+		count += 1;
+	}
+	count
+}
+
+#[ergo_pin]
+async fn next_if_peek1_stream() -> usize {
+	let mut stream = pin!(Peek1Stream::new(stream::iter(0..LEN).fuse()));
+	let mut count = 0;
+	while stream.as_mut().next_if(lt(LEN / 2)).await.is_some() {
+		count += 1;
+	}
+	count
+}
+
+fn peek_next(c: &mut Criterion) {
+	let mut group = c.benchmark_group("peek_next");
+	group.bench_function("Peekable", |b| {
+		b.iter(|| black_box(block_on(peek_next_peekable())))
+	});
+	group.bench_function("PeekStream<_, 1>", |b| {
+		b.iter(|| black_box(block_on(peek_next_peek_stream())))
+	});
+	group.bench_function("Peek1Stream", |b| {
+		b.iter(|| black_box(block_on(peek_next_peek1_stream())))
+	});
+	group.finish();
+}
+
+fn next_if(c: &mut Criterion) {
+	let mut group = c.benchmark_group("next_if");
+	group.throughput(Throughput::Elements((LEN / 2).into()));
+	group.bench_function("Peekable", |b| {
+		b.iter(|| black_box(block_on(next_if_peekable())))
+	});
+	group.bench_function("PeekStream<_, 1>", |b| {
+		b.iter(|| black_box(block_on(next_if_peek_stream())))
+	});
+	group.bench_function("Peek1Stream", |b| {
+		b.iter(|| black_box(block_on(next_if_peek1_stream())))
+	});
+	group.finish();
+}
+
+criterion_group!(benches, peek_next, next_if);
+criterion_main!(benches);
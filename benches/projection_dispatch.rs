@@ -0,0 +1,67 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ergo_pin::ergo_pin;
+use percolate::projection::{
+	from_async_mut, from_blocking_mut, ProjectionMut, StaticProjectionMut,
+};
+use pollster::block_on;
+
+const LEN: u32 = 1000;
+
+#[ergo_pin]
+async fn blocking_dyn() -> u32 {
+	let mut projection = pin!(from_blocking_mut(|x: u32| x * 2));
+	let mut sum = 0;
+	for i in 0..LEN {
+		sum += projection.as_mut().project(black_box(i)).await;
+	}
+	sum
+}
+
+#[ergo_pin]
+async fn blocking_static() -> u32 {
+	let mut projection = pin!(from_blocking_mut(|x: u32| x * 2));
+	let mut sum = 0;
+	for i in 0..LEN {
+		sum += projection.as_mut().project_static(black_box(i)).await;
+	}
+	sum
+}
+
+#[ergo_pin]
+async fn async_dyn() -> u32 {
+	let mut projection = pin!(from_async_mut(|x: u32| async move { x * 2 }));
+	let mut sum = 0;
+	for i in 0..LEN {
+		sum += projection.as_mut().project(black_box(i)).await;
+	}
+	sum
+}
+
+#[ergo_pin]
+async fn async_static() -> u32 {
+	let mut projection = pin!(from_async_mut(|x: u32| async move { x * 2 }));
+	let mut sum = 0;
+	for i in 0..LEN {
+		sum += projection.as_mut().project_static(black_box(i)).await;
+	}
+	sum
+}
+
+fn blocking(c: &mut Criterion) {
+	let mut group = c.benchmark_group("FusedBlockingMut");
+	group.bench_function("dyn", |b| b.iter(|| black_box(block_on(blocking_dyn()))));
+	group.bench_function("static", |b| {
+		b.iter(|| black_box(block_on(blocking_static())))
+	});
+	group.finish();
+}
+
+fn async_mut(c: &mut Criterion) {
+	let mut group = c.benchmark_group("AsyncMut");
+	group.bench_function("dyn", |b| b.iter(|| black_box(block_on(async_dyn()))));
+	group.bench_function("static", |b| b.iter(|| black_box(block_on(async_static()))));
+	group.finish();
+}
+
+criterion_group!(benches, blocking, async_mut);
+criterion_main!(benches);
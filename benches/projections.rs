@@ -0,0 +1,69 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ergo_pin::ergo_pin;
+use percolate::projection::{from_async_mut, from_ref_blocking_mut, ProjectionMut};
+use pollster::block_on;
+
+const LEN: u32 = 1000;
+
+async fn async_closure() -> u32 {
+	let projection = |x: u32| async move { x * 2 };
+	let mut sum = 0;
+	for i in 0..LEN {
+		sum += projection(black_box(i)).await;
+	}
+	sum
+}
+
+#[ergo_pin]
+async fn async_mut() -> u32 {
+	let mut projection = pin!(from_async_mut(|x: u32| async move { x * 2 }));
+	let mut sum = 0;
+	for i in 0..LEN {
+		sum += projection.as_mut().project(black_box(i)).await;
+	}
+	sum
+}
+
+async fn inline_closure() -> u32 {
+	let projection = |x: &u32| *x * 2;
+	let mut sum = 0;
+	for i in 0..LEN {
+		sum += projection(&black_box(i));
+	}
+	sum
+}
+
+#[ergo_pin]
+async fn fused_ref_blocking_mut() -> u32 {
+	let mut projection = pin!(from_ref_blocking_mut(|x: &u32| *x * 2));
+	let mut sum = 0;
+	for i in 0..LEN {
+		sum += projection.as_mut().project(&black_box(i)).await;
+	}
+	sum
+}
+
+fn asynchronous(c: &mut Criterion) {
+	let mut group = c.benchmark_group("async");
+	group.throughput(Throughput::Elements(LEN.into()));
+	group.bench_function("async closure", |b| {
+		b.iter(|| black_box(block_on(async_closure())))
+	});
+	group.bench_function("AsyncMut", |b| b.iter(|| black_box(block_on(async_mut()))));
+	group.finish();
+}
+
+fn blocking_ref(c: &mut Criterion) {
+	let mut group = c.benchmark_group("blocking_ref");
+	group.throughput(Throughput::Elements(LEN.into()));
+	group.bench_function("inline closure", |b| {
+		b.iter(|| black_box(block_on(inline_closure())))
+	});
+	group.bench_function("FusedRefBlockingMut", |b| {
+		b.iter(|| black_box(block_on(fused_ref_blocking_mut())))
+	});
+	group.finish();
+}
+
+criterion_group!(benches, asynchronous, blocking_ref);
+criterion_main!(benches);
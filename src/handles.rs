@@ -2,8 +2,10 @@
 
 use core::{
 	future::Future,
+	mem::{transmute, ManuallyDrop},
 	ops::{Deref, DerefMut},
 	pin::Pin,
+	ptr::NonNull,
 	task::{Context, Poll},
 };
 use futures_core::{FusedFuture, FusedStream, Stream};
@@ -20,13 +22,13 @@ impl<'a, F: ?Sized> RunOnce<'a, F> {
 		Self(f)
 	}
 }
-impl<'a> RunOnce<'a, dyn Runnable<(), ()>> {
+impl RunOnce<'_, dyn Runnable<(), ()>> {
 	pub fn run(self) {
-		self.0.run(())
+		self.0.run(());
 	}
 }
 
-/// Dereferences to [`Pin<&'a mut T>`](`Pin`) and optionally runs custom drop logic via stored [`RunOnce<dyn Runnable>`]`.
+/// Dereferences to [`Pin<&'a mut T>`](`Pin`) and optionally runs custom drop logic via stored [`RunOnce<dyn Runnable>`].
 pub struct PinHandleMut<'a, T: ?Sized> {
 	pin: Pin<&'a mut T>,
 	on_drop: Option<RunOnce<'a, dyn 'a + Runnable<(), ()>>>,
@@ -48,19 +50,19 @@ impl<'a, T: ?Sized> Deref for PinHandleMut<'a, T> {
 		&self.pin
 	}
 }
-impl<'a, T: ?Sized> DerefMut for PinHandleMut<'a, T> {
+impl<T: ?Sized> DerefMut for PinHandleMut<'_, T> {
 	fn deref_mut(&mut self) -> &mut Self::Target {
 		&mut self.pin
 	}
 }
 
-impl<'a, T: ?Sized> Drop for PinHandleMut<'a, T> {
+impl<T: ?Sized> Drop for PinHandleMut<'_, T> {
 	fn drop(&mut self) {
-		self.on_drop.take().map(RunOnce::run).unwrap_or_default()
+		self.on_drop.take().map(RunOnce::run).unwrap_or_default();
 	}
 }
 
-impl<'a, T: ?Sized> Future for PinHandleMut<'a, T>
+impl<T: ?Sized> Future for PinHandleMut<'_, T>
 where
 	T: Future,
 {
@@ -71,7 +73,7 @@ where
 	}
 }
 
-impl<'a, T: ?Sized> FusedFuture for PinHandleMut<'a, T>
+impl<T: ?Sized> FusedFuture for PinHandleMut<'_, T>
 where
 	T: FusedFuture,
 {
@@ -80,7 +82,7 @@ where
 	}
 }
 
-impl<'a, T: ?Sized> Stream for PinHandleMut<'a, T>
+impl<T: ?Sized> Stream for PinHandleMut<'_, T>
 where
 	T: Stream,
 {
@@ -95,7 +97,7 @@ where
 	}
 }
 
-impl<'a, T: ?Sized> FusedStream for PinHandleMut<'a, T>
+impl<T: ?Sized> FusedStream for PinHandleMut<'_, T>
 where
 	T: FusedStream,
 {
@@ -103,3 +105,44 @@ where
 		self.pin.is_terminated()
 	}
 }
+
+/// A [`PinHandleMut<dyn Future>`](`PinHandleMut`) with its lifetime erased,
+/// so that wrapping projections can store it next to the projection it borrows.
+///
+/// Dropping this runs the original handle's drop logic.
+pub(crate) struct ErasedFutureHandleMut<B> {
+	pin: NonNull<dyn Future<Output = B>>,
+	on_drop: Option<NonNull<dyn Runnable<(), ()>>>,
+}
+
+impl<B> ErasedFutureHandleMut<B> {
+	/// # Safety
+	///
+	/// The result **must** be dropped before the borrow behind `handle` is released.
+	pub(crate) unsafe fn new<'a>(handle: PinHandleMut<'a, dyn 'a + Future<Output = B>>) -> Self {
+		let mut handle = ManuallyDrop::new(handle);
+		let on_drop = handle.on_drop.take().map(|on_drop| {
+			transmute::<NonNull<dyn 'a + Runnable<(), ()>>, NonNull<dyn Runnable<(), ()>>>(
+				on_drop.0.into(),
+			)
+		});
+		Self {
+			pin: transmute::<NonNull<dyn 'a + Future<Output = B>>, NonNull<dyn Future<Output = B>>>(
+				Pin::get_unchecked_mut(handle.pin.as_mut()).into(),
+			),
+			on_drop,
+		}
+	}
+
+	pub(crate) fn poll(&mut self, cx: &mut Context<'_>) -> Poll<B> {
+		unsafe { Pin::new_unchecked(self.pin.as_mut()) }.poll(cx)
+	}
+}
+
+impl<B> Drop for ErasedFutureHandleMut<B> {
+	fn drop(&mut self) {
+		if let Some(on_drop) = self.on_drop.take() {
+			unsafe { on_drop.as_ref() }.run(());
+		}
+	}
+}
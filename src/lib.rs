@@ -47,12 +47,25 @@
 	clippy::single_match_else
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(doctest)]
 pub mod readme {
 	doc_comment::doctest!("../README.md");
 }
 
 pub mod handles;
+pub mod parse;
 pub mod predicate;
 pub mod projection;
+pub mod ring;
 pub mod stream;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod time;
+
+mod util;
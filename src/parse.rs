@@ -0,0 +1,527 @@
+//! Async parser combinators over [`PeekStream`].
+//!
+//! Parsers are [`ProjectionMut`]s from a [`Cursor`] towards a [`Parsed`] value,
+//! which hands the [`Cursor`] back along with the result.
+//! Closures returning [`Future`](`core::future::Future`)s are accepted directly through [`IntoParserMut`].
+//!
+//! A [`Cursor`] only advances tentatively through the [`PeekStream`]'s lookahead buffer,
+//! so [`choice`], [`optional`], [`many`], [`many1`] and [`separated_by`] can backtrack within `CAPACITY` items.
+//! Use [`Cursor::commit`] to actually consume the accepted items (which also prevents backtracking past them).
+//! Parsing further than `CAPACITY` items past the last commit fails with a [`LOOKAHEAD_EXCEEDED`] error,
+//! which the combinators don't backtrack from.
+//!
+//! # Example
+//!
+//! ```
+//! use ergo_pin::ergo_pin;
+//! use futures_util::stream::{self, StreamExt as _};
+//! use percolate::{
+//!     parse::{separated_by, token, Cursor, ParseError},
+//!     predicate::{chars::ascii_digit, ops::eq},
+//!     stream::PeekStream,
+//! };
+//! use pollster::block_on;
+//!
+//! #[ergo_pin]
+//! async fn digit_sum(input: &str) -> Result<u32, ParseError> {
+//!     let stream = pin!(PeekStream::<_, 8>::new(stream::iter(input.chars()).fuse()));
+//!     let (mut cursor, result) = separated_by(
+//!         Cursor::new(stream),
+//!         |cursor| async move {
+//!             let (mut cursor, digit) = token(cursor, "digit", ascii_digit()).await;
+//!             if digit.is_ok() {
+//!                 // Frees up the lookahead buffer, so that the input can be longer than `8` items.
+//!                 cursor.commit().await;
+//!             }
+//!             (cursor, digit)
+//!         },
+//!         |cursor| token(cursor, "`,`", eq(',')),
+//!         0,
+//!         |sum, digit: char| sum + digit.to_digit(10).unwrap(),
+//!     )
+//!     .await;
+//!     cursor.commit().await;
+//!     result
+//! }
+//!
+//! assert_eq!(block_on(digit_sum("1,2,3")), Ok(6));
+//! assert_eq!(block_on(digit_sum("1,2,3,4,5,6,7,8,9")), Ok(45));
+//! assert_eq!(block_on(digit_sum("1,2,")), Ok(3)); // The trailing `,` isn't consumed.
+//! assert_eq!(block_on(digit_sum("")), Ok(0));
+//! ```
+
+use crate::{
+	predicate::{IntoPredicateMut, PredicateMut},
+	projection::{Either, IntoProjectionMut, ProjectionMut},
+	stream::PeekStream,
+};
+use core::{
+	fmt::{self, Display, Formatter},
+	num::NonZeroUsize,
+	pin::Pin,
+};
+use ergo_pin::ergo_pin;
+use futures_core::FusedStream;
+use futures_util::StreamExt as _;
+
+/// The maximum number of distinct labels an [`Expected`] set retains.
+const EXPECTED_CAPACITY: usize = 8;
+
+/// The label of the [`ParseError`] returned by [`Cursor::peek`] once `CAPACITY` items were passed over without a [`.commit()`](`Cursor::commit`).
+pub const LOOKAHEAD_EXCEEDED: &str = "a commit within `CAPACITY` items of lookahead";
+
+/// A tentative read position in a [`PeekStream`].
+///
+/// Items passed over by the [`Cursor`] stay buffered in the [`PeekStream`] until [`.commit()`](`Cursor::commit`) is called.
+pub struct Cursor<'a, Input: FusedStream, const CAPACITY: usize> {
+	stream: Pin<&'a mut PeekStream<Input, CAPACITY>>,
+	committed: usize,
+	offset: usize,
+	/// Set by [`.peek()`](`Cursor::peek`) failing with [`LOOKAHEAD_EXCEEDED`], which prevents backtracking until the next commit.
+	exceeded: bool,
+}
+
+/// The result of a parser, along with the [`Cursor`] to continue from.
+///
+/// On failure, the [`Cursor`] may be left anywhere past the parser's starting position.
+/// Combinators that backtrack reset it themselves.
+pub type Parsed<'a, Input, O, const CAPACITY: usize> =
+	(Cursor<'a, Input, CAPACITY>, Result<O, ParseError>);
+
+/// alias: [`IntoProjectionMut<Cursor<'a, Input, CAPACITY>, Parsed<'a, Input, O, CAPACITY>, X>`](`IntoProjectionMut`)
+pub trait IntoParserMut<'a, Input: 'a + FusedStream, O, X, const CAPACITY: usize>:
+	IntoProjectionMut<Cursor<'a, Input, CAPACITY>, Parsed<'a, Input, O, CAPACITY>, X>
+{
+}
+impl<'a, P, Input: 'a + FusedStream, O, X, const CAPACITY: usize>
+	IntoParserMut<'a, Input, O, X, CAPACITY> for P
+where
+	P: IntoProjectionMut<Cursor<'a, Input, CAPACITY>, Parsed<'a, Input, O, CAPACITY>, X>,
+{
+}
+
+/// A failed parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+	position: usize,
+	expected: Expected,
+}
+
+impl ParseError {
+	/// Creates a new [`ParseError`] at `position` (in items since the respective [`Cursor`] was created),
+	/// expecting only `label`.
+	#[must_use]
+	pub fn new(position: usize, label: &'static str) -> Self {
+		let mut expected = Expected::default();
+		expected.insert(label);
+		Self { position, expected }
+	}
+
+	/// The position of the failure, in items since the respective [`Cursor`] was created.
+	#[must_use]
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// The labels of the alternatives that were tried at [`.position()`](`ParseError::position`).
+	#[must_use]
+	pub fn expected(&self) -> &Expected {
+		&self.expected
+	}
+
+	/// Keeps whichever error is further along, or the union of both [`Expected`] sets if they are at the same position.
+	#[must_use]
+	pub fn merge(mut self, other: Self) -> Self {
+		if other.position > self.position {
+			other
+		} else {
+			if other.position == self.position {
+				for label in other.expected.iter() {
+					self.expected.insert(label);
+				}
+			}
+			self
+		}
+	}
+}
+
+impl Display for ParseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "at position {}: expected ", self.position)?;
+		let mut labels = self.expected.iter();
+		match labels.next() {
+			Some(first) => {
+				f.write_str(first)?;
+				for label in labels {
+					write!(f, " or {}", label)?;
+				}
+				Ok(())
+			}
+			None => f.write_str("nothing"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// A small set of labels describing what a parser expected.
+///
+/// Only the first 8 distinct labels are retained.
+#[derive(Debug, Clone, Copy)]
+pub struct Expected {
+	labels: [&'static str; EXPECTED_CAPACITY],
+	len: usize,
+}
+
+impl Default for Expected {
+	fn default() -> Self {
+		Self {
+			labels: [""; EXPECTED_CAPACITY],
+			len: 0,
+		}
+	}
+}
+
+impl PartialEq for Expected {
+	fn eq(&self, other: &Self) -> bool {
+		self.len == other.len && self.iter().all(|label| other.contains(label))
+	}
+}
+impl Eq for Expected {}
+
+impl Expected {
+	/// Iterates over the retained labels in insertion order.
+	pub fn iter(&self) -> impl '_ + Iterator<Item = &'static str> {
+		self.labels[..self.len].iter().copied()
+	}
+
+	/// Checks whether `label` is in this set.
+	#[must_use]
+	pub fn contains(&self, label: &str) -> bool {
+		self.iter().any(|l| l == label)
+	}
+
+	/// The number of retained labels.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Checks whether this set is empty.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	fn insert(&mut self, label: &'static str) {
+		if self.len < EXPECTED_CAPACITY && !self.contains(label) {
+			self.labels[self.len] = label;
+			self.len += 1;
+		}
+	}
+}
+
+impl<'a, Input: FusedStream, const CAPACITY: usize> Cursor<'a, Input, CAPACITY> {
+	/// Creates a new [`Cursor`] at the start of `stream`, at position 0.
+	#[must_use]
+	pub fn new(stream: Pin<&'a mut PeekStream<Input, CAPACITY>>) -> Self {
+		Self {
+			stream,
+			committed: 0,
+			offset: 0,
+			exceeded: false,
+		}
+	}
+
+	/// The number of items passed over since this [`Cursor`] was created, whether committed or not.
+	#[must_use]
+	pub fn position(&self) -> usize {
+		self.committed + self.offset
+	}
+
+	/// Peeks the item at the current position.
+	///
+	/// # Errors
+	///
+	/// Iff `CAPACITY` items have been passed over without [`.commit()`](`Cursor::commit`),
+	/// with [`LOOKAHEAD_EXCEEDED`] as expectation.
+	///
+	/// The combinators in this module don't backtrack from that error.
+	pub async fn peek(&mut self) -> Result<Option<&Input::Item>, ParseError> {
+		if self.offset >= CAPACITY {
+			self.exceeded = true;
+			return Err(ParseError::new(self.position(), LOOKAHEAD_EXCEEDED));
+		}
+		let depth = match NonZeroUsize::new(self.offset + 1) {
+			Some(depth) => depth,
+			None => unreachable!(),
+		};
+		Ok(self.stream.as_mut().peek_n(depth).await)
+	}
+
+	/// Tentatively passes over the item at the current position.
+	pub fn advance(&mut self) {
+		self.offset += 1;
+	}
+
+	/// Consumes the items passed over so far from the underlying [`PeekStream`].
+	///
+	/// Combinators can't backtrack past a committed position.
+	pub async fn commit(&mut self) {
+		while self.offset > 0 {
+			self.stream.next().await;
+			self.offset -= 1;
+			self.committed += 1;
+		}
+		self.exceeded = false;
+	}
+
+	/// Returns the underlying [`PeekStream`], discarding uncommitted progress.
+	#[must_use]
+	pub fn into_inner(self) -> Pin<&'a mut PeekStream<Input, CAPACITY>> {
+		self.stream
+	}
+
+	/// Resets `self` to an earlier `position`, if that hasn't been committed yet
+	/// and the lookahead wasn't exceeded since.
+	fn rewind(&mut self, position: usize) -> bool {
+		if position >= self.committed && !self.exceeded {
+			self.offset = position - self.committed;
+			true
+		} else {
+			false
+		}
+	}
+}
+
+/// Accepts a single item that satisfies `predicate`, failing with `label` as expectation otherwise.
+///
+/// The accepted item is cloned out of the lookahead buffer.
+#[ergo_pin]
+pub async fn token<'a, Input, X, const CAPACITY: usize>(
+	mut cursor: Cursor<'a, Input, CAPACITY>,
+	label: &'static str,
+	predicate: impl IntoPredicateMut<Input::Item, X>,
+) -> Parsed<'a, Input, Input::Item, CAPACITY>
+where
+	Input: FusedStream,
+	Input::Item: Clone,
+{
+	let position = cursor.position();
+	let item = match cursor.peek().await {
+		Ok(Some(item)) => item,
+		Ok(None) => return (cursor, Err(ParseError::new(position, label))),
+		Err(error) => return (cursor, Err(error)),
+	};
+	if pin!(predicate.into_predicate_mut()).test(item).await {
+		let item = item.clone();
+		cursor.advance();
+		(cursor, Ok(item))
+	} else {
+		(cursor, Err(ParseError::new(position, label)))
+	}
+}
+
+/// Runs `first`, then `second`, failing if either fails.
+#[ergo_pin]
+pub async fn sequence<'a, Input, O1, O2, X1, X2, const CAPACITY: usize>(
+	cursor: Cursor<'a, Input, CAPACITY>,
+	first: impl IntoParserMut<'a, Input, O1, X1, CAPACITY>,
+	second: impl IntoParserMut<'a, Input, O2, X2, CAPACITY>,
+) -> Parsed<'a, Input, (O1, O2), CAPACITY>
+where
+	Input: FusedStream,
+{
+	let (cursor, first) = pin!(first.into_projection_mut()).project(cursor).await;
+	let first = match first {
+		Ok(first) => first,
+		Err(error) => return (cursor, Err(error)),
+	};
+	let (cursor, second) = pin!(second.into_projection_mut()).project(cursor).await;
+	(cursor, second.map(|second| (first, second)))
+}
+
+/// Runs `first`, or (after backtracking) `second` if `first` fails.
+///
+/// If both fail, their errors are [merged](`ParseError::merge`).
+/// If `first` commits before failing, `second` isn't tried.
+#[ergo_pin]
+pub async fn choice<'a, Input, O1, O2, X1, X2, const CAPACITY: usize>(
+	cursor: Cursor<'a, Input, CAPACITY>,
+	first: impl IntoParserMut<'a, Input, O1, X1, CAPACITY>,
+	second: impl IntoParserMut<'a, Input, O2, X2, CAPACITY>,
+) -> Parsed<'a, Input, Either<O1, O2>, CAPACITY>
+where
+	Input: FusedStream,
+{
+	let start = cursor.position();
+	let (mut cursor, first) = pin!(first.into_projection_mut()).project(cursor).await;
+	let first = match first {
+		Ok(first) => return (cursor, Ok(Either::Left(first))),
+		Err(error) => error,
+	};
+	if !cursor.rewind(start) {
+		return (cursor, Err(first));
+	}
+	let (cursor, second) = pin!(second.into_projection_mut()).project(cursor).await;
+	(
+		cursor,
+		second
+			.map(Either::Right)
+			.map_err(|second| first.merge(second)),
+	)
+}
+
+/// Runs `parser`, backtracking and succeeding with [`None`] if it fails.
+///
+/// If `parser` commits before failing, its error is returned instead.
+#[ergo_pin]
+pub async fn optional<'a, Input, O, X, const CAPACITY: usize>(
+	cursor: Cursor<'a, Input, CAPACITY>,
+	parser: impl IntoParserMut<'a, Input, O, X, CAPACITY>,
+) -> Parsed<'a, Input, Option<O>, CAPACITY>
+where
+	Input: FusedStream,
+{
+	let start = cursor.position();
+	let (mut cursor, result) = pin!(parser.into_projection_mut()).project(cursor).await;
+	match result {
+		Ok(output) => (cursor, Ok(Some(output))),
+		Err(error) => {
+			if cursor.rewind(start) {
+				(cursor, Ok(None))
+			} else {
+				(cursor, Err(error))
+			}
+		}
+	}
+}
+
+/// Runs `parser` as often as it succeeds (including zero times), folding its outputs into `init`.
+///
+/// The failed final attempt is backtracked.
+/// Stops early if `parser` succeeds without advancing.
+#[ergo_pin]
+pub async fn many<'a, Input, O, T, X, const CAPACITY: usize>(
+	cursor: Cursor<'a, Input, CAPACITY>,
+	parser: impl IntoParserMut<'a, Input, O, X, CAPACITY>,
+	init: T,
+	fold: impl FnMut(T, O) -> T,
+) -> Parsed<'a, Input, T, CAPACITY>
+where
+	Input: FusedStream,
+{
+	repeat(cursor, pin!(parser.into_projection_mut()), init, fold).await
+}
+
+/// Like [`many`], but fails if `parser` doesn't succeed at least once.
+#[ergo_pin]
+pub async fn many1<'a, Input, O, T, X, const CAPACITY: usize>(
+	cursor: Cursor<'a, Input, CAPACITY>,
+	parser: impl IntoParserMut<'a, Input, O, X, CAPACITY>,
+	init: T,
+	mut fold: impl FnMut(T, O) -> T,
+) -> Parsed<'a, Input, T, CAPACITY>
+where
+	Input: FusedStream,
+{
+	let mut parser = pin!(parser.into_projection_mut());
+	let (cursor, first) = parser.as_mut().project(cursor).await;
+	match first {
+		Ok(first) => repeat(cursor, parser, fold(init, first), fold).await,
+		Err(error) => (cursor, Err(error)),
+	}
+}
+
+/// Runs `item` as often as it succeeds (including zero times), separated by `separator`,
+/// folding the outputs of `item` into `init`.
+///
+/// A trailing separator isn't consumed.
+#[ergo_pin]
+pub async fn separated_by<'a, Input, O, S, T, X1, X2, const CAPACITY: usize>(
+	cursor: Cursor<'a, Input, CAPACITY>,
+	item: impl IntoParserMut<'a, Input, O, X1, CAPACITY>,
+	separator: impl IntoParserMut<'a, Input, S, X2, CAPACITY>,
+	init: T,
+	mut fold: impl FnMut(T, O) -> T,
+) -> Parsed<'a, Input, T, CAPACITY>
+where
+	Input: FusedStream,
+{
+	let mut item = pin!(item.into_projection_mut());
+	let mut separator = pin!(separator.into_projection_mut());
+
+	let start = cursor.position();
+	let (mut cursor, first) = item.as_mut().project(cursor).await;
+	let mut accumulator = match first {
+		Ok(first) => fold(init, first),
+		Err(error) => {
+			return if cursor.rewind(start) {
+				(cursor, Ok(init))
+			} else {
+				(cursor, Err(error))
+			};
+		}
+	};
+
+	loop {
+		let start = cursor.position();
+		let (next_cursor, result) = separator.as_mut().project(cursor).await;
+		cursor = next_cursor;
+		if let Err(error) = result {
+			return if cursor.rewind(start) {
+				(cursor, Ok(accumulator))
+			} else {
+				(cursor, Err(error))
+			};
+		}
+
+		let (next_cursor, result) = item.as_mut().project(cursor).await;
+		cursor = next_cursor;
+		match result {
+			Ok(output) => accumulator = fold(accumulator, output),
+			Err(error) => {
+				return if cursor.rewind(start) {
+					(cursor, Ok(accumulator))
+				} else {
+					(cursor, Err(error))
+				};
+			}
+		}
+		if cursor.position() == start {
+			return (cursor, Ok(accumulator));
+		}
+	}
+}
+
+async fn repeat<'a, Input, O, T, const CAPACITY: usize>(
+	mut cursor: Cursor<'a, Input, CAPACITY>,
+	mut parser: Pin<
+		&mut (impl ?Sized + ProjectionMut<Cursor<'a, Input, CAPACITY>, Parsed<'a, Input, O, CAPACITY>>),
+	>,
+	mut accumulator: T,
+	mut fold: impl FnMut(T, O) -> T,
+) -> Parsed<'a, Input, T, CAPACITY>
+where
+	Input: FusedStream,
+{
+	loop {
+		let start = cursor.position();
+		let (next_cursor, result) = parser.as_mut().project(cursor).await;
+		cursor = next_cursor;
+		match result {
+			Ok(output) => accumulator = fold(accumulator, output),
+			Err(error) => {
+				return if cursor.rewind(start) {
+					(cursor, Ok(accumulator))
+				} else {
+					(cursor, Err(error))
+				};
+			}
+		}
+		if cursor.position() == start {
+			return (cursor, Ok(accumulator));
+		}
+	}
+}
@@ -1,4 +1,4 @@
-//! <code><s>Mut</s>Predicate</code>s are `RefProjection`s towards [`bool`].  
+//! <code><s>Mut</s>Predicate</code>s are `RefProjection`s towards [`bool`].\
 //! `MutPredicate`s are `MutProjection`s towards [`bool`].
 //!
 //! Prefer using `Into` `Predicate` types over `Projection` types in your function signatures to make them more intuitively readable.
@@ -36,6 +36,341 @@ use crate::{
 use core::{future::Future, pin::Pin};
 use futures_core::FusedFuture;
 
+/// Implements the projection traits and the operators for a predicate type in this module's children that implements [`Check`].
+///
+/// By default, the predicate converts into [`Checked`] through the `Into…` traits, so that it can stay zero-sized.
+/// Predicates declared `with_outcome` store their own `outcome: Option<bool>` and are [`FusedPredicateMut`]s themselves.
+macro_rules! predicate_impls {
+	($Name:ident$(<$($G:tt),*>)?) => {
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoRefProjectionMut<T, bool, $crate::predicate::Checked<Self>> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoRefProjMut = $crate::predicate::Checked<Self>;
+			fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+				$crate::predicate::Checked::new(self)
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoMutProjectionMut<T, bool, $crate::predicate::Checked<Self>> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoMutProjMut = $crate::predicate::Checked<Self>;
+			fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+				$crate::predicate::Checked::new(self)
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoFusedRefProjectionMut<T, bool, $crate::predicate::Checked<Self>> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoFusedRefProjMut = $crate::predicate::Checked<Self>;
+			fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+				$crate::predicate::Checked::new(self)
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoFusedMutProjectionMut<T, bool, $crate::predicate::Checked<Self>> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoFusedMutProjMut = $crate::predicate::Checked<Self>;
+			fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+				$crate::predicate::Checked::new(self)
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoProjectionMut<&T, bool, $crate::predicate::Checked<Self>> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoProjMut = $crate::predicate::Checked<Self>;
+			fn into_projection_mut(self) -> Self::IntoProjMut {
+				$crate::predicate::Checked::new(self)
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoProjectionMut<&mut T, bool, $crate::predicate::Checked<Self>> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoProjMut = $crate::predicate::Checked<Self>;
+			fn into_projection_mut(self) -> Self::IntoProjMut {
+				$crate::predicate::Checked::new(self)
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoFusedProjectionMut<&T, bool, $crate::predicate::Checked<Self>> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoFusedProjMut = $crate::predicate::Checked<Self>;
+			fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+				$crate::predicate::Checked::new(self)
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoFusedProjectionMut<&mut T, bool, $crate::predicate::Checked<Self>> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoFusedProjMut = $crate::predicate::Checked<Self>;
+			fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+				$crate::predicate::Checked::new(self)
+			}
+		}
+
+		predicate_impls!(@operators $Name$(<$($G),*>)?);
+	};
+
+	(with_outcome $Name:ident$(<$($G:tt),*>)?) => {
+		predicate_impls!(@projection $Name$(<$($G),*>)?);
+		predicate_impls!(@operators $Name$(<$($G),*>)?);
+	};
+
+	(@projection $Name:ident$(<$($G:tt),*>)?) => {
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoRefProjectionMut<T, bool, Self> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoRefProjMut = Self;
+			fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+				self
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoMutProjectionMut<T, bool, Self> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoMutProjMut = Self;
+			fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+				self
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoFusedRefProjectionMut<T, bool, Self> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoFusedRefProjMut = Self;
+			fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+				self
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoFusedMutProjectionMut<T, bool, Self> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoFusedMutProjMut = Self;
+			fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+				self
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoProjectionMut<&T, bool, Self> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoProjMut = Self;
+			fn into_projection_mut(self) -> Self::IntoProjMut {
+				self
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoProjectionMut<&mut T, bool, Self> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoProjMut = Self;
+			fn into_projection_mut(self) -> Self::IntoProjMut {
+				self
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoFusedProjectionMut<&T, bool, Self> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoFusedProjMut = Self;
+			fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+				self
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::IntoFusedProjectionMut<&mut T, bool, Self> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			type IntoFusedProjMut = Self;
+			fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+				self
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::PollProjectionMut<&T, bool> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			unsafe fn start(self: ::core::pin::Pin<&mut Self>, value: &T) {
+				// Safety: Nothing is structurally pinned.
+				let this = self.get_unchecked_mut();
+				this.outcome = Some($crate::predicate::Check::<T>::check(this, value));
+			}
+
+			fn poll_result(
+				self: ::core::pin::Pin<&mut Self>,
+				_: &mut ::core::task::Context<'_>,
+			) -> ::core::task::Poll<bool> {
+				let this = unsafe {
+					// Safety: Nothing is structurally pinned.
+					self.get_unchecked_mut()
+				};
+				::core::task::Poll::Ready(this.outcome.take().expect(concat!(
+					"`",
+					stringify!($Name),
+					"::poll_result` called without a projection in progress"
+				)))
+			}
+
+			fn clear(self: ::core::pin::Pin<&mut Self>) {
+				unsafe {
+					// Safety: Nothing is structurally pinned.
+					self.get_unchecked_mut()
+				}
+				.outcome = None;
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::PollProjectionMut<&mut T, bool> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			unsafe fn start(self: ::core::pin::Pin<&mut Self>, value: &mut T) {
+				$crate::projection::PollProjectionMut::<&T, bool>::start(self, value);
+			}
+
+			fn poll_result(
+				self: ::core::pin::Pin<&mut Self>,
+				cx: &mut ::core::task::Context<'_>,
+			) -> ::core::task::Poll<bool> {
+				$crate::projection::PollProjectionMut::<&T, bool>::poll_result(self, cx)
+			}
+
+			fn clear(self: ::core::pin::Pin<&mut Self>) {
+				$crate::projection::PollProjectionMut::<&T, bool>::clear(self);
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::FusedPollProjectionMut<&T, bool> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			fn is_terminated(&self) -> bool {
+				self.outcome.is_none()
+			}
+		}
+
+		impl<$($($G,)*)? T: ?Sized> $crate::projection::FusedPollProjectionMut<&mut T, bool> for $Name$(<$($G),*>)?
+		where
+			Self: $crate::predicate::Check<T>,
+		{
+			fn is_terminated(&self) -> bool {
+				self.outcome.is_none()
+			}
+		}
+	};
+
+	(@operators $Name:ident$(<$($G:tt),*>)?) => {
+		impl<$($($G,)*)?> ::core::ops::Not for $Name$(<$($G),*>)? {
+			type Output = $crate::predicate::ops::Not<Self>;
+			fn not(self) -> Self::Output {
+				$crate::predicate::ops::Not::new(self)
+			}
+		}
+
+		impl<$($($G,)*)? Rhs> ::core::ops::BitAnd<Rhs> for $Name$(<$($G),*>)? {
+			type Output = $crate::predicate::ops::And<Self, Rhs>;
+			fn bitand(self, rhs: Rhs) -> Self::Output {
+				$crate::predicate::ops::And::new(self, rhs)
+			}
+		}
+
+		impl<$($($G,)*)? Rhs> ::core::ops::BitOr<Rhs> for $Name$(<$($G),*>)? {
+			type Output = $crate::predicate::ops::Or<Self, Rhs>;
+			fn bitor(self, rhs: Rhs) -> Self::Output {
+				$crate::predicate::ops::Or::new(self, rhs)
+			}
+		}
+	};
+}
+
+/// The synchronous part of each predicate in this module's children.
+pub(crate) trait Check<T: ?Sized> {
+	fn check(&self, value: &T) -> bool;
+}
+
+/// A [`FusedPredicateMut`] that evaluates one of the predicates in [`ops`], [`chars`] or [`bytes`] synchronously,
+/// so the resulting future is always ready on its first poll.
+///
+/// Those predicates convert into this through the `Into…` traits,
+/// which lets them stay zero-sized where they don't store any parameters.
+pub struct Checked<C> {
+	check: C,
+	outcome: Option<bool>,
+}
+
+impl<C> Checked<C> {
+	pub(crate) fn new(check: C) -> Self {
+		Self {
+			check,
+			outcome: None,
+		}
+	}
+}
+
+impl<C, T: ?Sized> Check<T> for Checked<C>
+where
+	C: Check<T>,
+{
+	fn check(&self, value: &T) -> bool {
+		self.check.check(value)
+	}
+}
+
+// region: projection impls
+predicate_impls!(@projection Checked<C>);
+// endregion
+
+/// Declares zero-sized predicate types over `$T` that each forward to one of its `&self` methods,
+/// along with constructor functions.
+macro_rules! class_predicates {
+	($T:ty: $($(#[$attr:meta])* $Name:ident $function:ident $method:ident;)*) => {$(
+		$(#[$attr])*
+		#[derive(Clone, Copy, Debug)]
+		pub struct $Name;
+		impl $crate::predicate::Check<$T> for $Name {
+			fn check(&self, value: &$T) -> bool {
+				value.$method()
+			}
+		}
+		predicate_impls!($Name);
+
+		$(#[$attr])*
+		#[must_use]
+		pub fn $function() -> $Name {
+			$Name
+		}
+	)*};
+}
+
+pub mod bytes;
+pub mod chars;
+pub mod ops;
+
 /// alias: [`RefProjectionMut<T, bool>`]
 pub trait PredicateMut<T: ?Sized>: RefProjectionMut<T, bool> + MutPredicateMut<T> {
 	fn test<'a>(
@@ -0,0 +1,133 @@
+//! Nameable predicates for byte classes, e.g. for tokenizers.
+//!
+//! Those with parameters, like [`Digit`], are [`FusedPredicateMut<u8>`](`super::FusedPredicateMut`)s themselves.
+//! Those without are zero-sized and convert into [`Checked`](`super::Checked`) through
+//! [`IntoFusedPredicateMut<u8, _>`](`super::IntoFusedPredicateMut`) and the other `Into…` traits instead,
+//! so accept that bound where they should be usable.
+//!
+//! Like those in [`ops`](`super::ops`), these can be combined using the `!`, `&` and `|` operators.
+//!
+//! # Example
+//!
+//! ```
+//! use ergo_pin::ergo_pin;
+//! use percolate::predicate::{
+//!     bytes::{ascii_whitespace, in_range},
+//!     IntoPredicateMut, PredicateMut,
+//! };
+//! use pollster::block_on;
+//!
+//! #[ergo_pin]
+//! async fn is_separator(value: u8) -> bool {
+//!     let predicate = ascii_whitespace() | in_range(b','..=b'.');
+//!     pin!(IntoPredicateMut::<u8, _>::into_predicate_mut(predicate))
+//!         .test(&value)
+//!         .await
+//! }
+//!
+//! assert!(block_on(is_separator(b' ')));
+//! assert!(block_on(is_separator(b'-')));
+//! assert!(!block_on(is_separator(b'a')));
+//! ```
+
+use super::Check;
+
+pub use super::ops::{in_range, InRange};
+
+class_predicates! {
+	u8:
+
+	/// ASCII bytes, see [`u8::is_ascii`].
+	Ascii ascii is_ascii;
+	/// ASCII alphabetic bytes, see [`u8::is_ascii_alphabetic`].
+	AsciiAlphabetic ascii_alphabetic is_ascii_alphabetic;
+	/// ASCII alphanumeric bytes, see [`u8::is_ascii_alphanumeric`].
+	AsciiAlphanumeric ascii_alphanumeric is_ascii_alphanumeric;
+	/// ASCII decimal digits, see [`u8::is_ascii_digit`].
+	AsciiDigit ascii_digit is_ascii_digit;
+	/// ASCII hexadecimal digits, see [`u8::is_ascii_hexdigit`].
+	AsciiHexdigit ascii_hexdigit is_ascii_hexdigit;
+	/// ASCII whitespace, see [`u8::is_ascii_whitespace`].
+	AsciiWhitespace ascii_whitespace is_ascii_whitespace;
+	/// ASCII punctuation, see [`u8::is_ascii_punctuation`].
+	AsciiPunctuation ascii_punctuation is_ascii_punctuation;
+	/// ASCII graphic bytes, see [`u8::is_ascii_graphic`].
+	AsciiGraphic ascii_graphic is_ascii_graphic;
+	/// ASCII uppercase bytes, see [`u8::is_ascii_uppercase`].
+	AsciiUppercase ascii_uppercase is_ascii_uppercase;
+	/// ASCII lowercase bytes, see [`u8::is_ascii_lowercase`].
+	AsciiLowercase ascii_lowercase is_ascii_lowercase;
+	/// ASCII control bytes, see [`u8::is_ascii_control`].
+	AsciiControl ascii_control is_ascii_control;
+}
+
+/// ASCII digits in a given radix, see [`char::is_digit`].
+#[derive(Clone, Copy, Debug)]
+pub struct Digit {
+	radix: u32,
+	outcome: Option<bool>,
+}
+impl Check<u8> for Digit {
+	fn check(&self, value: &u8) -> bool {
+		char::from(*value).is_digit(self.radix)
+	}
+}
+predicate_impls!(with_outcome Digit);
+
+/// Bytes contained in a slice.
+#[derive(Clone, Copy, Debug)]
+pub struct OneOf {
+	bytes: &'static [u8],
+	outcome: Option<bool>,
+}
+impl Check<u8> for OneOf {
+	fn check(&self, value: &u8) -> bool {
+		self.bytes.contains(value)
+	}
+}
+predicate_impls!(with_outcome OneOf);
+
+/// Bytes not contained in a slice.
+#[derive(Clone, Copy, Debug)]
+pub struct NoneOf {
+	bytes: &'static [u8],
+	outcome: Option<bool>,
+}
+impl Check<u8> for NoneOf {
+	fn check(&self, value: &u8) -> bool {
+		!self.bytes.contains(value)
+	}
+}
+predicate_impls!(with_outcome NoneOf);
+
+/// ASCII digits in the given `radix`, see [`char::is_digit`].
+///
+/// # Panics
+///
+/// Iff `radix` is not in `2..=36`.
+#[must_use]
+pub fn digit(radix: u32) -> Digit {
+	assert!((2..=36).contains(&radix), "`radix` must be in `2..=36`");
+	Digit {
+		radix,
+		outcome: None,
+	}
+}
+
+/// Bytes contained in `bytes`.
+#[must_use]
+pub fn one_of(bytes: &'static [u8]) -> OneOf {
+	OneOf {
+		bytes,
+		outcome: None,
+	}
+}
+
+/// Bytes not contained in `bytes`.
+#[must_use]
+pub fn none_of(bytes: &'static [u8]) -> NoneOf {
+	NoneOf {
+		bytes,
+		outcome: None,
+	}
+}
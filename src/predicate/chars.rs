@@ -0,0 +1,148 @@
+//! Nameable predicates for character classes, e.g. for tokenizers.
+//!
+//! Those with parameters, like [`Digit`], are [`FusedPredicateMut<char>`](`super::FusedPredicateMut`)s themselves.
+//! Those without are zero-sized and convert into [`Checked`](`super::Checked`) through
+//! [`IntoFusedPredicateMut<char, _>`](`super::IntoFusedPredicateMut`) and the other `Into…` traits instead,
+//! so accept that bound where they should be usable.
+//!
+//! Like those in [`ops`](`super::ops`), these can be combined using the `!`, `&` and `|` operators.
+//!
+//! # Example
+//!
+//! ```
+//! use ergo_pin::ergo_pin;
+//! use percolate::predicate::{
+//!     chars::{alphabetic, digit, one_of},
+//!     IntoPredicateMut, PredicateMut,
+//! };
+//! use pollster::block_on;
+//!
+//! #[ergo_pin]
+//! async fn is_identifier_continue(value: char) -> bool {
+//!     let predicate = alphabetic() | digit(10) | one_of("_-");
+//!     pin!(IntoPredicateMut::<char, _>::into_predicate_mut(predicate))
+//!         .test(&value)
+//!         .await
+//! }
+//!
+//! assert!(block_on(is_identifier_continue('ä')));
+//! assert!(block_on(is_identifier_continue('_')));
+//! assert!(!block_on(is_identifier_continue(' ')));
+//! ```
+
+use super::Check;
+
+pub use super::ops::{in_range, InRange};
+
+class_predicates! {
+	char:
+
+	/// Unicode alphabetic characters, see [`char::is_alphabetic`].
+	Alphabetic alphabetic is_alphabetic;
+	/// Unicode alphanumeric characters, see [`char::is_alphanumeric`].
+	Alphanumeric alphanumeric is_alphanumeric;
+	/// Unicode numeric characters, see [`char::is_numeric`].
+	Numeric numeric is_numeric;
+	/// Unicode whitespace, see [`char::is_whitespace`].
+	Whitespace whitespace is_whitespace;
+	/// Unicode uppercase characters, see [`char::is_uppercase`].
+	Uppercase uppercase is_uppercase;
+	/// Unicode lowercase characters, see [`char::is_lowercase`].
+	Lowercase lowercase is_lowercase;
+	/// Unicode control characters, see [`char::is_control`].
+	Control control is_control;
+
+	/// ASCII characters, see [`char::is_ascii`].
+	Ascii ascii is_ascii;
+	/// ASCII alphabetic characters, see [`char::is_ascii_alphabetic`].
+	AsciiAlphabetic ascii_alphabetic is_ascii_alphabetic;
+	/// ASCII alphanumeric characters, see [`char::is_ascii_alphanumeric`].
+	AsciiAlphanumeric ascii_alphanumeric is_ascii_alphanumeric;
+	/// ASCII decimal digits, see [`char::is_ascii_digit`].
+	AsciiDigit ascii_digit is_ascii_digit;
+	/// ASCII hexadecimal digits, see [`char::is_ascii_hexdigit`].
+	AsciiHexdigit ascii_hexdigit is_ascii_hexdigit;
+	/// ASCII whitespace, see [`char::is_ascii_whitespace`].
+	AsciiWhitespace ascii_whitespace is_ascii_whitespace;
+	/// ASCII punctuation, see [`char::is_ascii_punctuation`].
+	AsciiPunctuation ascii_punctuation is_ascii_punctuation;
+	/// ASCII graphic characters, see [`char::is_ascii_graphic`].
+	AsciiGraphic ascii_graphic is_ascii_graphic;
+	/// ASCII uppercase characters, see [`char::is_ascii_uppercase`].
+	AsciiUppercase ascii_uppercase is_ascii_uppercase;
+	/// ASCII lowercase characters, see [`char::is_ascii_lowercase`].
+	AsciiLowercase ascii_lowercase is_ascii_lowercase;
+	/// ASCII control characters, see [`char::is_ascii_control`].
+	AsciiControl ascii_control is_ascii_control;
+}
+
+/// Digits in a given radix, see [`char::is_digit`].
+#[derive(Clone, Copy, Debug)]
+pub struct Digit {
+	radix: u32,
+	outcome: Option<bool>,
+}
+impl Check<char> for Digit {
+	fn check(&self, value: &char) -> bool {
+		value.is_digit(self.radix)
+	}
+}
+predicate_impls!(with_outcome Digit);
+
+/// Characters contained in a string.
+#[derive(Clone, Copy, Debug)]
+pub struct OneOf {
+	chars: &'static str,
+	outcome: Option<bool>,
+}
+impl Check<char> for OneOf {
+	fn check(&self, value: &char) -> bool {
+		self.chars.contains(*value)
+	}
+}
+predicate_impls!(with_outcome OneOf);
+
+/// Characters not contained in a string.
+#[derive(Clone, Copy, Debug)]
+pub struct NoneOf {
+	chars: &'static str,
+	outcome: Option<bool>,
+}
+impl Check<char> for NoneOf {
+	fn check(&self, value: &char) -> bool {
+		!self.chars.contains(*value)
+	}
+}
+predicate_impls!(with_outcome NoneOf);
+
+/// Digits in the given `radix`, see [`char::is_digit`].
+///
+/// # Panics
+///
+/// Iff `radix` is not in `2..=36`.
+#[must_use]
+pub fn digit(radix: u32) -> Digit {
+	assert!((2..=36).contains(&radix), "`radix` must be in `2..=36`");
+	Digit {
+		radix,
+		outcome: None,
+	}
+}
+
+/// Characters contained in `chars`.
+#[must_use]
+pub fn one_of(chars: &'static str) -> OneOf {
+	OneOf {
+		chars,
+		outcome: None,
+	}
+}
+
+/// Characters not contained in `chars`.
+#[must_use]
+pub fn none_of(chars: &'static str) -> NoneOf {
+	NoneOf {
+		chars,
+		outcome: None,
+	}
+}
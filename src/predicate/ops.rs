@@ -0,0 +1,351 @@
+//! Nameable predicates for common tests.
+//!
+//! Each of these evaluates synchronously, so the resulting future is always ready on its first poll.
+//!
+//! Those with parameters, like [`Gt`], are [`FusedPredicateMut`](`super::FusedPredicateMut`)s themselves.
+//! The zero-sized ones, like [`Always`], instead convert into [`Checked`](`super::Checked`) through
+//! [`IntoFusedPredicateMut`](`super::IntoFusedPredicateMut`) and the other `Into…` traits,
+//! so accept that (rather than a predicate) where they should be usable.
+//!
+//! They (and those in sibling modules) can be combined using the `!`, `&` and `|` operators,
+//! resulting in [`Not`], [`And`] and [`Or`] respectively.
+//!
+//! # Example
+//!
+//! ```
+//! use core::pin::Pin;
+//! use futures_core::FusedStream;
+//! use percolate::{predicate::ops::gt, stream::PeekStream};
+//!
+//! async fn next_if_large<S: FusedStream<Item = u32>>(stream: Pin<&mut PeekStream<S, 1>>) -> Option<u32> {
+//!     stream.next_if(gt(5)).await
+//! }
+//! ```
+
+use super::Check;
+use core::ops::RangeBounds;
+
+/// Tests for equality with a stored value.
+#[derive(Clone, Copy, Debug)]
+pub struct Eq<V> {
+	value: V,
+	outcome: Option<bool>,
+}
+impl<V, T: ?Sized + PartialEq<V>> Check<T> for Eq<V> {
+	fn check(&self, value: &T) -> bool {
+		value.eq(&self.value)
+	}
+}
+predicate_impls!(with_outcome Eq<V>);
+
+/// Tests for inequality with a stored value.
+#[derive(Clone, Copy, Debug)]
+pub struct Ne<V> {
+	value: V,
+	outcome: Option<bool>,
+}
+impl<V, T: ?Sized + PartialEq<V>> Check<T> for Ne<V> {
+	fn check(&self, value: &T) -> bool {
+		value.ne(&self.value)
+	}
+}
+predicate_impls!(with_outcome Ne<V>);
+
+/// Tests whether values are less than a stored value.
+#[derive(Clone, Copy, Debug)]
+pub struct Lt<V> {
+	value: V,
+	outcome: Option<bool>,
+}
+impl<V, T: ?Sized + PartialOrd<V>> Check<T> for Lt<V> {
+	fn check(&self, value: &T) -> bool {
+		value.lt(&self.value)
+	}
+}
+predicate_impls!(with_outcome Lt<V>);
+
+/// Tests whether values are less than or equal to a stored value.
+#[derive(Clone, Copy, Debug)]
+pub struct Le<V> {
+	value: V,
+	outcome: Option<bool>,
+}
+impl<V, T: ?Sized + PartialOrd<V>> Check<T> for Le<V> {
+	fn check(&self, value: &T) -> bool {
+		value.le(&self.value)
+	}
+}
+predicate_impls!(with_outcome Le<V>);
+
+/// Tests whether values are greater than a stored value.
+#[derive(Clone, Copy, Debug)]
+pub struct Gt<V> {
+	value: V,
+	outcome: Option<bool>,
+}
+impl<V, T: ?Sized + PartialOrd<V>> Check<T> for Gt<V> {
+	fn check(&self, value: &T) -> bool {
+		value.gt(&self.value)
+	}
+}
+predicate_impls!(with_outcome Gt<V>);
+
+/// Tests whether values are greater than or equal to a stored value.
+#[derive(Clone, Copy, Debug)]
+pub struct Ge<V> {
+	value: V,
+	outcome: Option<bool>,
+}
+impl<V, T: ?Sized + PartialOrd<V>> Check<T> for Ge<V> {
+	fn check(&self, value: &T) -> bool {
+		value.ge(&self.value)
+	}
+}
+predicate_impls!(with_outcome Ge<V>);
+
+/// Tests whether values are contained in a stored [`RangeBounds`].
+#[derive(Clone, Copy, Debug)]
+pub struct InRange<R> {
+	range: R,
+	outcome: Option<bool>,
+}
+impl<R, T: ?Sized + PartialOrd> Check<T> for InRange<R>
+where
+	R: RangeBounds<T>,
+{
+	fn check(&self, value: &T) -> bool {
+		self.range.contains(value)
+	}
+}
+predicate_impls!(with_outcome InRange<R>);
+
+/// Tests whether values are equal to any element of a borrowed slice.
+#[derive(Clone, Copy, Debug)]
+pub struct ContainedIn<'a, V> {
+	values: &'a [V],
+	outcome: Option<bool>,
+}
+impl<V, T: ?Sized + PartialEq<V>> Check<T> for ContainedIn<'_, V> {
+	fn check(&self, value: &T) -> bool {
+		self.values.iter().any(|v| value.eq(v))
+	}
+}
+predicate_impls!(with_outcome ContainedIn<'a, V>);
+
+/// Accepts any value.
+#[derive(Clone, Copy, Debug)]
+pub struct Always;
+impl<T: ?Sized> Check<T> for Always {
+	fn check(&self, _: &T) -> bool {
+		true
+	}
+}
+predicate_impls!(Always);
+
+/// Rejects any value.
+#[derive(Clone, Copy, Debug)]
+pub struct Never;
+impl<T: ?Sized> Check<T> for Never {
+	fn check(&self, _: &T) -> bool {
+		false
+	}
+}
+predicate_impls!(Never);
+
+/// Tests whether [`Option`]s are [`Some`].
+#[derive(Clone, Copy, Debug)]
+pub struct IsSome;
+impl<T> Check<Option<T>> for IsSome {
+	fn check(&self, value: &Option<T>) -> bool {
+		value.is_some()
+	}
+}
+predicate_impls!(IsSome);
+
+/// Tests whether [`Result`]s are [`Ok`].
+#[derive(Clone, Copy, Debug)]
+pub struct IsOk;
+impl<T, E> Check<Result<T, E>> for IsOk {
+	fn check(&self, value: &Result<T, E>) -> bool {
+		value.is_ok()
+	}
+}
+predicate_impls!(IsOk);
+
+/// Negates a predicate.
+#[derive(Clone, Copy, Debug)]
+pub struct Not<P> {
+	predicate: P,
+}
+impl<P> Not<P> {
+	pub(crate) fn new(predicate: P) -> Self {
+		Self { predicate }
+	}
+}
+impl<P, T: ?Sized> Check<T> for Not<P>
+where
+	P: Check<T>,
+{
+	fn check(&self, value: &T) -> bool {
+		!self.predicate.check(value)
+	}
+}
+predicate_impls!(Not<P>);
+
+/// Accepts values that satisfy both predicates. Short-circuiting.
+#[derive(Clone, Copy, Debug)]
+pub struct And<L, R> {
+	left: L,
+	right: R,
+}
+impl<L, R> And<L, R> {
+	pub(crate) fn new(left: L, right: R) -> Self {
+		Self { left, right }
+	}
+}
+impl<L, R, T: ?Sized> Check<T> for And<L, R>
+where
+	L: Check<T>,
+	R: Check<T>,
+{
+	fn check(&self, value: &T) -> bool {
+		self.left.check(value) && self.right.check(value)
+	}
+}
+predicate_impls!(And<L, R>);
+
+/// Accepts values that satisfy either predicate. Short-circuiting.
+#[derive(Clone, Copy, Debug)]
+pub struct Or<L, R> {
+	left: L,
+	right: R,
+}
+impl<L, R> Or<L, R> {
+	pub(crate) fn new(left: L, right: R) -> Self {
+		Self { left, right }
+	}
+}
+impl<L, R, T: ?Sized> Check<T> for Or<L, R>
+where
+	L: Check<T>,
+	R: Check<T>,
+{
+	fn check(&self, value: &T) -> bool {
+		self.left.check(value) || self.right.check(value)
+	}
+}
+predicate_impls!(Or<L, R>);
+
+/// `|x| x == value`
+#[must_use]
+pub fn eq<V>(value: V) -> Eq<V> {
+	Eq {
+		value,
+		outcome: None,
+	}
+}
+
+/// `|x| x != value`
+#[must_use]
+pub fn ne<V>(value: V) -> Ne<V> {
+	Ne {
+		value,
+		outcome: None,
+	}
+}
+
+/// `|x| x < value`
+#[must_use]
+pub fn lt<V>(value: V) -> Lt<V> {
+	Lt {
+		value,
+		outcome: None,
+	}
+}
+
+/// `|x| x <= value`
+#[must_use]
+pub fn le<V>(value: V) -> Le<V> {
+	Le {
+		value,
+		outcome: None,
+	}
+}
+
+/// `|x| x > value`
+#[must_use]
+pub fn gt<V>(value: V) -> Gt<V> {
+	Gt {
+		value,
+		outcome: None,
+	}
+}
+
+/// `|x| x >= value`
+#[must_use]
+pub fn ge<V>(value: V) -> Ge<V> {
+	Ge {
+		value,
+		outcome: None,
+	}
+}
+
+/// `|x| range.contains(x)`
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::predicate::{ops::in_range, IntoPredicateMut, PredicateMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn is_digit(value: u8) -> bool {
+///     pin!(IntoPredicateMut::<u8, _>::into_predicate_mut(in_range(b'0'..=b'9')))
+///         .test(&value)
+///         .await
+/// }
+///
+/// assert!(block_on(is_digit(b'5')));
+/// assert!(!block_on(is_digit(b'a')));
+/// ```
+#[must_use]
+pub fn in_range<R>(range: R) -> InRange<R> {
+	InRange {
+		range,
+		outcome: None,
+	}
+}
+
+/// `|x| values.contains(x)`
+#[must_use]
+pub fn contained_in<V>(values: &[V]) -> ContainedIn<'_, V> {
+	ContainedIn {
+		values,
+		outcome: None,
+	}
+}
+
+/// `|_| true`
+#[must_use]
+pub fn always() -> Always {
+	Always
+}
+
+/// `|_| false`
+#[must_use]
+pub fn never() -> Never {
+	Never
+}
+
+/// [`Option::is_some`]
+#[must_use]
+pub fn is_some() -> IsSome {
+	IsSome
+}
+
+/// [`Result::is_ok`]
+#[must_use]
+pub fn is_ok() -> IsOk {
+	IsOk
+}
@@ -1,18 +1,15 @@
-use super::{FusedProjectionMut, IntoFusedProjectionMut, IntoProjectionMut, ProjectionMut};
-use crate::handles::{PinHandleMut, RunOnce, Runnable};
+use super::{FusedPollProjectionMut, IntoFusedProjectionMut, IntoProjectionMut, PollProjectionMut};
 use core::{
 	cell::UnsafeCell,
 	marker::PhantomData,
-	mem::transmute,
 	pin::Pin,
 	task::{Context, Poll},
 };
 use futures_core::{FusedFuture, Future};
 use pin_project::pin_project;
-use tap::Pipe;
 
 /// [`From<`](`From`)[`P: FnMut(A) -> `](`FnMut`)`F: `[`〚Fused〛`](`FusedFuture`)[`Future<Output = B>`](`Future`)[`>`](`FnMut`)[`>`](`From`)
-/// and [`〚Fused〛`](`FusedProjectionMut`)[`ProjectionMut<A, B>`]
+/// and [`〚Fused〛`](`super::FusedProjectionMut`)[`ProjectionMut<A, B>`](`super::ProjectionMut`)
 #[pin_project]
 pub struct AsyncMut<P, A, F, B>
 where
@@ -26,14 +23,14 @@ where
 }
 
 // region: threading
-/// Only `P` is persistent. Whenever `F` is instantiated, there is a [`PinHandleMut`]`: `[`!Send`](`Send`) that drops it before the mutable borrow is released.
+/// `A` is only passed through.
 unsafe impl<P, A, F, B> Send for AsyncMut<P, A, F, B>
 where
 	P: Send + FnMut(A) -> F,
-	F: Future<Output = B>,
+	F: Send + Future<Output = B>,
 {
 }
-/// [`&AsyncMut`](`AsyncMut`) is immutable and doesn't (publicly) allow access to stored data.
+/// [`&AsyncMut`](`AsyncMut`) only exposes whether a future is stored, not `P` or `F` themselves.
 unsafe impl<P, A, F, B> Sync for AsyncMut<P, A, F, B>
 where
 	P: FnMut(A) -> F,
@@ -63,134 +60,44 @@ where
 	}
 }
 
-impl<P, A, F, B> ProjectionMut<A, B> for AsyncMut<P, A, F, B>
+impl<P, A, F, B> PollProjectionMut<A, B> for AsyncMut<P, A, F, B>
 where
 	P: FnMut(A) -> F,
 	F: Future<Output = B>,
 {
-	#[must_use]
-	fn project(
-		mut self: Pin<&mut Self>,
-		value: A,
-	) -> PinHandleMut<'_, dyn '_ + Future<Output = B>> {
-		let this = self.as_mut().project();
-		unsafe { *this.future.get() = Some((this.projection)(value)) };
-		let this = self.into_ref();
-		PinHandleMut::new(
-			unsafe { transmute::<Pin<&Self>, Pin<&mut AsyncMutFuture<P, A, F, B>>>(this) },
-			Some(unsafe {
-				RunOnce::new(transmute::<Pin<&Self>, &ClearAsyncMut<P, A, F, B>>(this))
-			}),
-		)
+	unsafe fn start(self: Pin<&mut Self>, value: A) {
+		let this = self.project();
+		*this.future.get() = Some((this.projection)(value));
 	}
-}
-impl<P, A, F, B> FusedProjectionMut<A, B> for AsyncMut<P, A, F, B>
-where
-	P: FnMut(A) -> F,
-	F: FusedFuture<Output = B>,
-{
-	fn project_fused(
-		mut self: Pin<&mut Self>,
-		value: A,
-	) -> PinHandleMut<'_, dyn '_ + FusedFuture<Output = B>> {
-		let this = self.as_mut().project();
-		unsafe { *this.future.get() = Some((this.projection)(value)) };
-		let this = self.into_ref();
-		PinHandleMut::new(
-			unsafe { transmute::<Pin<&Self>, Pin<&mut AsyncMutFuture<P, A, F, B>>>(this) },
-			Some(unsafe {
-				RunOnce::new(transmute::<Pin<&Self>, &ClearAsyncMut<P, A, F, B>>(this))
-			}),
-		)
-	}
-}
-// endregion
-// region: future
-#[repr(transparent)]
-#[pin_project]
-struct AsyncMutFuture<P, A, F, B>(
-	#[pin] UnsafeCell<AsyncMut<P, A, F, B>>,
-	PhantomData<*const ()>,
-)
-where
-	P: FnMut(A) -> F,
-	F: Future<Output = B>;
 
-/// `F` may exist now, but `P` isn't accessed.
-unsafe impl<P, A, F, B> Send for AsyncMutFuture<P, A, F, B>
-where
-	P: FnMut(A) -> F,
-	F: Send + Future<Output = B>,
-{
-}
-/// [`&AsyncMutFuture`] allows access to `F: `[`FusedFuture`], but `P` isn't accessed.
-unsafe impl<P, A, F, B> Sync for AsyncMutFuture<P, A, F, B>
-where
-	P: FnMut(A) -> F,
-	F: Sync + Future<Output = B>,
-{
-}
-
-impl<P, A, F, B> Future for AsyncMutFuture<P, A, F, B>
-where
-	P: FnMut(A) -> F,
-	F: Future<Output = B>,
-{
-	type Output = B;
-	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-		let blocking = unsafe { Pin::new_unchecked(&*self.project().0.get()) };
-		unsafe { &mut *blocking.future.get() }
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		let mut future = unsafe { Pin::new_unchecked(&mut *self.project().future.get()) };
+		let output = future
 			.as_mut()
-			.expect("unreachable")
-			.pipe(|x| unsafe { Pin::new_unchecked(x) })
-			.poll(cx)
+			.as_pin_mut()
+			.expect("`AsyncMut::poll_result` called without a projection in progress")
+			.poll(cx);
+		if output.is_ready() {
+			// Dropped right away, so that `.is_terminated()` doesn't have to access `F` through `&self`.
+			future.set(None);
+		}
+		output
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		unsafe { Pin::new_unchecked(&mut *self.project().future.get()) }.set(None);
 	}
 }
-impl<P, A, F, B> FusedFuture for AsyncMutFuture<P, A, F, B>
+impl<P, A, F, B> FusedPollProjectionMut<A, B> for AsyncMut<P, A, F, B>
 where
 	P: FnMut(A) -> F,
 	F: FusedFuture<Output = B>,
 {
 	fn is_terminated(&self) -> bool {
-		let blocking = unsafe { &*self.0.get() };
-		unsafe { &*blocking.future.get() }
-			.as_ref()
-			.expect("unreachable")
-			.is_terminated()
+		unsafe { &*self.future.get() }.is_none()
 	}
 }
 // endregion
-// region: clear
-#[repr(transparent)]
-#[pin_project]
-struct ClearAsyncMut<P, A, F, B>(#[pin] AsyncMut<P, A, F, B>, PhantomData<*mut ()>)
-where
-	P: FnMut(A) -> F,
-	F: Future<Output = B>;
-impl<P, A, F, B> Runnable<(), ()> for ClearAsyncMut<P, A, F, B>
-where
-	P: FnMut(A) -> F,
-	F: Future<Output = B>,
-{
-	fn run(&self, _: ()) {
-		unsafe { Pin::new_unchecked(&mut *self.0.future.get()) }.set(None)
-	}
-}
-/// `F` may exist now, but P isn't accessed.
-unsafe impl<P, A, F, B> Send for ClearAsyncMut<P, A, F, B>
-where
-	P: FnMut(A) -> F,
-	F: Send + Future<Output = B>,
-{
-}
-/// [`&ClearAsyncMut`] is immutable and doesn't (publicly) allow access to stored data.
-unsafe impl<P, A, F, B> Sync for ClearAsyncMut<P, A, F, B>
-where
-	P: FnMut(A) -> F,
-	F: Future<Output = B>,
-{
-}
-// endregion
 // region: conversions
 impl<P, A, F, B> From<P> for AsyncMut<P, A, F, B>
 where
@@ -223,12 +130,12 @@ where
 	F: FusedFuture<Output = B>,
 {
 	type IntoFusedProjMut = AsyncMut<P, A, F, B>;
-	fn into_fused_projection_mut(self) -> Self::IntoProjMut {
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
 		self.into()
 	}
 }
 
-/// [`FnMut(A) -> `](`FnMut`)[`〚Fused〛`](`FusedFuture`)[`Future<Output = B>`](`Future`) → [`〚Fused〛`](`FusedProjectionMut`)[`ProjectionMut<A, B>`]
+/// [`FnMut(A) -> `](`FnMut`)[`〚Fused〛`](`FusedFuture`)[`Future<Output = B>`](`Future`) → [`〚Fused〛`](`super::FusedProjectionMut`)[`ProjectionMut<A, B>`](`super::ProjectionMut`)
 #[must_use]
 pub fn from_async_mut<P, A, F, B>(projection: P) -> AsyncMut<P, A, F, B>
 where
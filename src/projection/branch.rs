@@ -0,0 +1,320 @@
+use super::{
+	FusedPollProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
+	IntoFusedRefProjectionMut, IntoMutProjectionMut, IntoProjectionMut, IntoRefProjectionMut,
+	PollProjectionMut, RefProjectionMut,
+};
+use crate::{
+	handles::ErasedFutureHandleMut,
+	predicate::{IntoPredicateMut, PredicateMut},
+};
+use core::{
+	cell::UnsafeCell,
+	pin::Pin,
+	ptr::NonNull,
+	task::{Context, Poll},
+};
+use pin_project::pin_project;
+
+/// [`PredicateMut<T>`] and two [`RefProjectionMut<T, B>`] → [`FusedRefProjectionMut<T, B>`](`super::FusedRefProjectionMut`)
+///
+/// Each value is first tested against `predicate`,
+/// then projected through `left` if that resulted in `true` and through `right` otherwise.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::projection::{branch, RefProjectionMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn abs(value: i32) -> i32 {
+///     pin!(branch(|x: &i32| *x < 0, |x: &i32| -x, |x: &i32| *x))
+///         .project_ref(&value)
+///         .await
+/// }
+///
+/// assert_eq!(block_on(abs(-1)), 1);
+/// assert_eq!(block_on(abs(1)), 1);
+/// ```
+#[pin_project(!Unpin)]
+pub struct Branch<Pred, L, R, T: ?Sized, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	// Declared before the projections so that a leaked handle is dropped first.
+	state: UnsafeCell<BranchState<T, B>>,
+	#[pin]
+	predicate: Pred,
+	#[pin]
+	left: UnsafeCell<L>,
+	#[pin]
+	right: UnsafeCell<R>,
+}
+
+enum BranchState<T: ?Sized, B> {
+	Idle,
+	Testing(NonNull<T>, ErasedFutureHandleMut<bool>),
+	Projecting(ErasedFutureHandleMut<B>),
+	Done,
+}
+
+impl<Pred, L, R, T: ?Sized, B> Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	#[must_use]
+	pub fn new(predicate: Pred, left: L, right: R) -> Self {
+		Self {
+			state: BranchState::Idle.into(),
+			predicate,
+			left: left.into(),
+			right: right.into(),
+		}
+	}
+}
+
+// region: threading
+/// The erased handles in `state` only borrow the projections and the borrowed input.
+unsafe impl<Pred, L, R, T: ?Sized, B> Send for Branch<Pred, L, R, T, B>
+where
+	Pred: Send + PredicateMut<T>,
+	L: Send + RefProjectionMut<T, B>,
+	R: Send + RefProjectionMut<T, B>,
+	T: Sync,
+	B: Send,
+{
+}
+/// [`&Branch`](`Branch`) exposes nothing but `.is_terminated()`, which checks `state`'s variant without touching the borrowed `T`, the predicate or either branch.
+unsafe impl<Pred, L, R, T: ?Sized, B> Sync for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+}
+// endregion
+// region: projection impls
+impl<Pred, L, R, T: ?Sized, B> IntoRefProjectionMut<T, B, Self> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	type IntoRefProjMut = Self;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		self
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> IntoMutProjectionMut<T, B, Self> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	type IntoMutProjMut = Self;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		self
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> IntoFusedRefProjectionMut<T, B, Self> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	type IntoFusedRefProjMut = Self;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		self
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> IntoFusedMutProjectionMut<T, B, Self> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	type IntoFusedMutProjMut = Self;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		self
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> IntoProjectionMut<&T, B, Self> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> IntoProjectionMut<&mut T, B, Self> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> IntoFusedProjectionMut<&T, B, Self> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> IntoFusedProjectionMut<&mut T, B, Self> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> PollProjectionMut<&T, B> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &T) {
+		let this = self.project();
+		let state = this.state.get_mut();
+		*state = BranchState::Idle;
+		*state = BranchState::Testing(
+			value.into(),
+			// Safety: Dropped by `.clear()` or when `self` is dropped.
+			ErasedFutureHandleMut::new(this.predicate.test(value)),
+		);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		let branch = self.into_ref().get_ref();
+		let state = unsafe { &mut *branch.state.get() };
+		loop {
+			match state {
+				BranchState::Testing(value, test) => {
+					let is_left = match test.poll(cx) {
+						Poll::Ready(is_left) => is_left,
+						Poll::Pending => return Poll::Pending,
+					};
+					let value = unsafe { value.as_ref() };
+					*state = BranchState::Idle;
+					let future = if is_left {
+						unsafe { Pin::new_unchecked(&mut *branch.left.get()) }.project_ref(value)
+					} else {
+						unsafe { Pin::new_unchecked(&mut *branch.right.get()) }.project_ref(value)
+					};
+					*state = BranchState::Projecting(unsafe {
+						// Safety: Dropped by `.clear()` or when `branch` is dropped.
+						ErasedFutureHandleMut::new(future)
+					});
+				}
+				BranchState::Projecting(future) => {
+					let output = match future.poll(cx) {
+						Poll::Ready(output) => output,
+						Poll::Pending => return Poll::Pending,
+					};
+					*state = BranchState::Done;
+					return Poll::Ready(output);
+				}
+				BranchState::Idle | BranchState::Done => {
+					panic!("`Branch::poll_result` called without a projection in progress")
+				}
+			}
+		}
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		*self.project().state.get_mut() = BranchState::Idle;
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> PollProjectionMut<&mut T, B> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &mut T) {
+		PollProjectionMut::<&T, B>::start(self, value);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		PollProjectionMut::<&T, B>::poll_result(self, cx)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		PollProjectionMut::<&T, B>::clear(self);
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> FusedPollProjectionMut<&T, B> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	fn is_terminated(&self) -> bool {
+		matches!(
+			unsafe { &*self.state.get() },
+			BranchState::Idle | BranchState::Done
+		)
+	}
+}
+
+impl<Pred, L, R, T: ?Sized, B> FusedPollProjectionMut<&mut T, B> for Branch<Pred, L, R, T, B>
+where
+	Pred: PredicateMut<T>,
+	L: RefProjectionMut<T, B>,
+	R: RefProjectionMut<T, B>,
+{
+	fn is_terminated(&self) -> bool {
+		FusedPollProjectionMut::<&T, B>::is_terminated(self)
+	}
+}
+// endregion
+// region: conversions
+/// [`IntoPredicateMut<T, X>`] and two [`IntoRefProjectionMut<T, B, X>`] → [`FusedRefProjectionMut<T, B>`](`super::FusedRefProjectionMut`)
+#[must_use]
+pub fn branch<Pred, L, R, X1, X2, X3, T: ?Sized, B>(
+	predicate: Pred,
+	left: L,
+	right: R,
+) -> Branch<Pred::IntoPredMut, L::IntoRefProjMut, R::IntoRefProjMut, T, B>
+where
+	Pred: IntoPredicateMut<T, X1>,
+	L: IntoRefProjectionMut<T, B, X2>,
+	R: IntoRefProjectionMut<T, B, X3>,
+{
+	Branch::new(
+		predicate.into_predicate_mut(),
+		left.into_ref_projection_mut(),
+		right.into_ref_projection_mut(),
+	)
+}
+// endregion
@@ -0,0 +1,321 @@
+use super::{
+	FusedMutProjection, FusedPollProjectionMut, FusedProjection, FusedRefProjection,
+	IntoFusedMutProjection, IntoFusedMutProjectionMut, IntoFusedProjection, IntoFusedProjectionMut,
+	IntoFusedRefProjection, IntoFusedRefProjectionMut, IntoMutProjection, IntoMutProjectionMut,
+	IntoProjection, IntoProjectionMut, IntoRefProjection, IntoRefProjectionMut, MutProjection,
+	PollProjectionMut, Projection, RefProjection,
+};
+use crate::handles::PinHandleMut;
+use core::{
+	pin::Pin,
+	task::{Context, Poll},
+};
+use futures_core::{FusedFuture, Future};
+use pin_project::pin_project;
+
+/// One of two alternatives.
+///
+/// This implements each projection trait that both `L` and `R` implement (with the same parameters),
+/// so that either of two different projections can be used in one place without boxing it into a `dyn` one.
+///
+/// The `…ProjectionMut` traits are forwarded through [`PollProjectionMut`].
+/// Wrap projections that only implement [`ProjectionMut`](`super::ProjectionMut`) in [`Fuse`](`super::Fuse`) first.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::projection::{from_blocking_mut, Either, ProjectionMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn project(negate: bool, value: i32) -> i32 {
+///     let projection = if negate {
+///         Either::Left(from_blocking_mut(|x: i32| -x))
+///     } else {
+///         Either::Right(from_blocking_mut(|x: i32| x))
+///     };
+///     pin!(projection).project(value).await
+/// }
+///
+/// assert_eq!(block_on(project(true, 1)), -1);
+/// assert_eq!(block_on(project(false, 1)), 1);
+/// ```
+#[pin_project(project = EitherProj, project_ref = EitherProjRef)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+	Left(#[pin] L),
+	Right(#[pin] R),
+}
+
+impl<L, R> Either<L, R> {
+	#[must_use]
+	pub fn is_left(&self) -> bool {
+		matches!(self, Either::Left(_))
+	}
+
+	#[must_use]
+	pub fn is_right(&self) -> bool {
+		matches!(self, Either::Right(_))
+	}
+
+	#[must_use]
+	pub fn left(self) -> Option<L> {
+		match self {
+			Either::Left(left) => Some(left),
+			Either::Right(_) => None,
+		}
+	}
+
+	#[must_use]
+	pub fn right(self) -> Option<R> {
+		match self {
+			Either::Left(_) => None,
+			Either::Right(right) => Some(right),
+		}
+	}
+
+	#[must_use]
+	pub fn as_ref(&self) -> Either<&L, &R> {
+		match self {
+			Either::Left(left) => Either::Left(left),
+			Either::Right(right) => Either::Right(right),
+		}
+	}
+
+	#[must_use]
+	pub fn as_mut(&mut self) -> Either<&mut L, &mut R> {
+		match self {
+			Either::Left(left) => Either::Left(left),
+			Either::Right(right) => Either::Right(right),
+		}
+	}
+
+	#[must_use]
+	pub fn flip(self) -> Either<R, L> {
+		match self {
+			Either::Left(left) => Either::Right(left),
+			Either::Right(right) => Either::Left(right),
+		}
+	}
+}
+
+impl<T> Either<T, T> {
+	#[must_use]
+	pub fn into_inner(self) -> T {
+		match self {
+			Either::Left(inner) | Either::Right(inner) => inner,
+		}
+	}
+}
+
+// region: projection impls
+impl<L, R, A, B> Projection<A, B> for Either<L, R>
+where
+	L: Projection<A, B> + PollProjectionMut<A, B>,
+	R: Projection<A, B> + PollProjectionMut<A, B>,
+{
+	fn project(self: Pin<&Self>, value: A) -> PinHandleMut<'_, dyn '_ + Future<Output = B>> {
+		match self.project_ref() {
+			EitherProjRef::Left(left) => left.project(value),
+			EitherProjRef::Right(right) => right.project(value),
+		}
+	}
+}
+
+impl<L, R, A, B> FusedProjection<A, B> for Either<L, R>
+where
+	L: FusedProjection<A, B> + FusedPollProjectionMut<A, B>,
+	R: FusedProjection<A, B> + FusedPollProjectionMut<A, B>,
+{
+	fn project_fused(
+		self: Pin<&Self>,
+		value: A,
+	) -> PinHandleMut<'_, dyn '_ + FusedFuture<Output = B>> {
+		match self.project_ref() {
+			EitherProjRef::Left(left) => left.project_fused(value),
+			EitherProjRef::Right(right) => right.project_fused(value),
+		}
+	}
+}
+
+impl<L, R, A, B> PollProjectionMut<A, B> for Either<L, R>
+where
+	L: PollProjectionMut<A, B>,
+	R: PollProjectionMut<A, B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: A) {
+		match self.project() {
+			EitherProj::Left(left) => left.start(value),
+			EitherProj::Right(right) => right.start(value),
+		}
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		match self.project() {
+			EitherProj::Left(left) => left.poll_result(cx),
+			EitherProj::Right(right) => right.poll_result(cx),
+		}
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		match self.project() {
+			EitherProj::Left(left) => left.clear(),
+			EitherProj::Right(right) => right.clear(),
+		}
+	}
+}
+
+impl<L, R, A, B> FusedPollProjectionMut<A, B> for Either<L, R>
+where
+	L: FusedPollProjectionMut<A, B>,
+	R: FusedPollProjectionMut<A, B>,
+{
+	fn is_terminated(&self) -> bool {
+		match self {
+			Either::Left(left) => left.is_terminated(),
+			Either::Right(right) => right.is_terminated(),
+		}
+	}
+}
+// endregion
+// region: conversions
+impl<L, R, A: ?Sized, B> IntoRefProjection<A, B, Self> for Either<L, R>
+where
+	L: RefProjection<A, B>
+		+ for<'a> PollProjectionMut<&'a A, B>
+		+ for<'a> PollProjectionMut<&'a mut A, B>,
+	R: RefProjection<A, B>
+		+ for<'a> PollProjectionMut<&'a A, B>
+		+ for<'a> PollProjectionMut<&'a mut A, B>,
+{
+	type IntoRefProj = Self;
+	fn into_ref_projection(self) -> Self::IntoRefProj {
+		self
+	}
+}
+
+impl<L, R, A: ?Sized, B> IntoMutProjection<A, B, Self> for Either<L, R>
+where
+	L: MutProjection<A, B> + for<'a> PollProjectionMut<&'a mut A, B>,
+	R: MutProjection<A, B> + for<'a> PollProjectionMut<&'a mut A, B>,
+{
+	type IntoMutProj = Self;
+	fn into_mut_projection(self) -> Self::IntoMutProj {
+		self
+	}
+}
+
+impl<L, R, A: ?Sized, B> IntoFusedRefProjection<A, B, Self> for Either<L, R>
+where
+	L: FusedRefProjection<A, B>
+		+ for<'a> FusedPollProjectionMut<&'a A, B>
+		+ for<'a> FusedPollProjectionMut<&'a mut A, B>,
+	R: FusedRefProjection<A, B>
+		+ for<'a> FusedPollProjectionMut<&'a A, B>
+		+ for<'a> FusedPollProjectionMut<&'a mut A, B>,
+{
+	type IntoFusedRefProj = Self;
+	fn into_fused_ref_projection(self) -> Self::IntoFusedRefProj {
+		self
+	}
+}
+
+impl<L, R, A: ?Sized, B> IntoFusedMutProjection<A, B, Self> for Either<L, R>
+where
+	L: FusedMutProjection<A, B> + for<'a> FusedPollProjectionMut<&'a mut A, B>,
+	R: FusedMutProjection<A, B> + for<'a> FusedPollProjectionMut<&'a mut A, B>,
+{
+	type IntoFusedMutProj = Self;
+	fn into_fused_mut_projection(self) -> Self::IntoFusedMutProj {
+		self
+	}
+}
+
+impl<L, R, A: ?Sized, B> IntoRefProjectionMut<A, B, Self> for Either<L, R>
+where
+	L: for<'a> PollProjectionMut<&'a A, B> + for<'a> PollProjectionMut<&'a mut A, B>,
+	R: for<'a> PollProjectionMut<&'a A, B> + for<'a> PollProjectionMut<&'a mut A, B>,
+{
+	type IntoRefProjMut = Self;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		self
+	}
+}
+
+impl<L, R, A: ?Sized, B> IntoMutProjectionMut<A, B, Self> for Either<L, R>
+where
+	L: for<'a> PollProjectionMut<&'a mut A, B>,
+	R: for<'a> PollProjectionMut<&'a mut A, B>,
+{
+	type IntoMutProjMut = Self;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		self
+	}
+}
+
+impl<L, R, A: ?Sized, B> IntoFusedRefProjectionMut<A, B, Self> for Either<L, R>
+where
+	L: for<'a> FusedPollProjectionMut<&'a A, B> + for<'a> FusedPollProjectionMut<&'a mut A, B>,
+	R: for<'a> FusedPollProjectionMut<&'a A, B> + for<'a> FusedPollProjectionMut<&'a mut A, B>,
+{
+	type IntoFusedRefProjMut = Self;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		self
+	}
+}
+
+impl<L, R, A: ?Sized, B> IntoFusedMutProjectionMut<A, B, Self> for Either<L, R>
+where
+	L: for<'a> FusedPollProjectionMut<&'a mut A, B>,
+	R: for<'a> FusedPollProjectionMut<&'a mut A, B>,
+{
+	type IntoFusedMutProjMut = Self;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		self
+	}
+}
+impl<L, R, A, B> IntoProjection<A, B, Self> for Either<L, R>
+where
+	L: Projection<A, B> + PollProjectionMut<A, B>,
+	R: Projection<A, B> + PollProjectionMut<A, B>,
+{
+	type IntoProj = Self;
+	fn into_projection(self) -> Self::IntoProj {
+		self
+	}
+}
+
+impl<L, R, A, B> IntoFusedProjection<A, B, Self> for Either<L, R>
+where
+	L: FusedProjection<A, B> + FusedPollProjectionMut<A, B>,
+	R: FusedProjection<A, B> + FusedPollProjectionMut<A, B>,
+{
+	type IntoFusedProj = Self;
+	fn into_fused_projection(self) -> Self::IntoFusedProj {
+		self
+	}
+}
+
+impl<L, R, A, B> IntoProjectionMut<A, B, Self> for Either<L, R>
+where
+	L: PollProjectionMut<A, B>,
+	R: PollProjectionMut<A, B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<L, R, A, B> IntoFusedProjectionMut<A, B, Self> for Either<L, R>
+where
+	L: FusedPollProjectionMut<A, B>,
+	R: FusedPollProjectionMut<A, B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+// endregion
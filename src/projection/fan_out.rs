@@ -0,0 +1,512 @@
+use super::{
+	FusedPollProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
+	IntoFusedRefProjectionMut, IntoMutProjectionMut, IntoProjectionMut, IntoRefProjectionMut,
+	PollProjectionMut, RefProjectionMut,
+};
+use crate::handles::{ErasedFutureHandleMut, PinHandleMut};
+use core::{
+	cell::UnsafeCell,
+	marker::PhantomData,
+	mem,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use futures_core::Future;
+use pin_project::pin_project;
+
+/// A group of [`RefProjectionMut`]s that can be driven concurrently to produce a combined `B`.
+///
+/// This is implemented for tuples of up to 12 [`RefProjectionMut<A, Bi>`] as follows:
+///
+/// - towards the tuple `(B1, B2, …)`, completing once each projection has completed, and
+/// - towards [`Result<(B1, B2, …), E>`](`Result`) where each `Bi` is a [`Result<_, E>`](`Result`),
+///   completing early with the first [`Err`].
+///
+/// See [`FanOut`] for the matching projection.
+pub trait Join<A: ?Sized, B> {
+	/// The running state of one fan-out.
+	type Slots;
+
+	/// Starts each projection in `self` on `value`.
+	///
+	/// # Safety
+	///
+	/// The result **must** be dropped before the borrows of `self` and `value` are released.
+	unsafe fn start(self: Pin<&mut Self>, value: &A) -> Self::Slots;
+
+	/// Polls each running projection in `slots` and assembles the output once possible.
+	///
+	/// Mustn't be called again after returning [`Poll::Ready`].
+	fn poll_join(slots: &mut Self::Slots, cx: &mut Context<'_>) -> Poll<B>;
+}
+
+/// The state of one projection in a [`Join`] implementation.
+pub struct JoinSlot<F, O>(JoinSlotState<F, O>);
+
+enum JoinSlotState<F, O> {
+	Running(ErasedFutureHandleMut<F>),
+	Ready(O),
+	Taken,
+}
+
+impl<F, O> JoinSlot<F, O> {
+	/// # Safety
+	///
+	/// See [`ErasedFutureHandleMut::new`].
+	unsafe fn new<'a>(handle: PinHandleMut<'a, dyn 'a + Future<Output = F>>) -> Self {
+		Self(JoinSlotState::Running(ErasedFutureHandleMut::new(handle)))
+	}
+
+	fn take(&mut self) -> O {
+		match mem::replace(&mut self.0, JoinSlotState::Taken) {
+			JoinSlotState::Ready(output) => output,
+			JoinSlotState::Running(_) | JoinSlotState::Taken => {
+				unreachable!("`JoinSlot::take` called without output")
+			}
+		}
+	}
+}
+
+impl<T> JoinSlot<T, T> {
+	/// Returns whether the output is available.
+	fn poll(&mut self, cx: &mut Context<'_>) -> bool {
+		match &mut self.0 {
+			JoinSlotState::Running(future) => match future.poll(cx) {
+				Poll::Ready(output) => {
+					self.0 = JoinSlotState::Ready(output);
+					true
+				}
+				Poll::Pending => false,
+			},
+			JoinSlotState::Ready(_) => true,
+			JoinSlotState::Taken => unreachable!("`JoinSlot::poll` called after `take`"),
+		}
+	}
+}
+
+impl<T, E> JoinSlot<Result<T, E>, T> {
+	/// Returns whether the output is available, or the first error.
+	fn try_poll(&mut self, cx: &mut Context<'_>) -> Result<bool, E> {
+		match &mut self.0 {
+			JoinSlotState::Running(future) => match future.poll(cx) {
+				Poll::Ready(output) => {
+					self.0 = JoinSlotState::Ready(output?);
+					Ok(true)
+				}
+				Poll::Pending => Ok(false),
+			},
+			JoinSlotState::Ready(_) => Ok(true),
+			JoinSlotState::Taken => unreachable!("`JoinSlot::try_poll` called after `take`"),
+		}
+	}
+}
+
+macro_rules! join_tuple {
+	($($P:ident $B:ident $i:tt),+) => {
+		impl<A: ?Sized, $($P, $B),+> Join<A, ($($B,)+)> for ($($P,)+)
+		where
+			$($P: RefProjectionMut<A, $B>),+
+		{
+			type Slots = ($(JoinSlot<$B, $B>,)+);
+
+			unsafe fn start(self: Pin<&mut Self>, value: &A) -> Self::Slots {
+				let this = self.get_unchecked_mut();
+				($(JoinSlot::new(Pin::new_unchecked(&mut this.$i).project_ref(value)),)+)
+			}
+
+			fn poll_join(slots: &mut Self::Slots, cx: &mut Context<'_>) -> Poll<($($B,)+)> {
+				let mut ready = true;
+				$(ready &= slots.$i.poll(cx);)+
+				if ready {
+					Poll::Ready(($(slots.$i.take(),)+))
+				} else {
+					Poll::Pending
+				}
+			}
+		}
+
+		impl<A: ?Sized, E, $($P, $B),+> Join<A, Result<($($B,)+), E>> for ($($P,)+)
+		where
+			$($P: RefProjectionMut<A, Result<$B, E>>),+
+		{
+			type Slots = ($(JoinSlot<Result<$B, E>, $B>,)+);
+
+			unsafe fn start(self: Pin<&mut Self>, value: &A) -> Self::Slots {
+				let this = self.get_unchecked_mut();
+				($(JoinSlot::new(Pin::new_unchecked(&mut this.$i).project_ref(value)),)+)
+			}
+
+			fn poll_join(
+				slots: &mut Self::Slots,
+				cx: &mut Context<'_>,
+			) -> Poll<Result<($($B,)+), E>> {
+				let mut ready = true;
+				$(match slots.$i.try_poll(cx) {
+					Ok(slot_ready) => ready &= slot_ready,
+					Err(error) => return Poll::Ready(Err(error)),
+				})+
+				if ready {
+					Poll::Ready(Ok(($(slots.$i.take(),)+)))
+				} else {
+					Poll::Pending
+				}
+			}
+		}
+	};
+}
+
+join_tuple!(P1 B1 0);
+join_tuple!(P1 B1 0, P2 B2 1);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3, P5 B5 4);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3, P5 B5 4, P6 B6 5);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3, P5 B5 4, P6 B6 5, P7 B7 6);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3, P5 B5 4, P6 B6 5, P7 B7 6, P8 B8 7);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3, P5 B5 4, P6 B6 5, P7 B7 6, P8 B8 7, P9 B9 8);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3, P5 B5 4, P6 B6 5, P7 B7 6, P8 B8 7, P9 B9 8, P10 B10 9);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3, P5 B5 4, P6 B6 5, P7 B7 6, P8 B8 7, P9 B9 8, P10 B10 9, P11 B11 10);
+join_tuple!(P1 B1 0, P2 B2 1, P3 B3 2, P4 B4 3, P5 B5 4, P6 B6 5, P7 B7 6, P8 B8 7, P9 B9 8, P10 B10 9, P11 B11 10, P12 B12 11);
+
+/// [`Join<A, B>`] → [`FusedRefProjectionMut<A, B>`](`super::FusedRefProjectionMut`)
+///
+/// Projects each value through all projections in `T` concurrently, without allocating.
+///
+/// Tuples of [`RefProjectionMut`]s convert into this through the `Into…Projection…` traits directly,
+/// but [`fan_out`] and [`try_fan_out`] are usually easier on type inference.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::projection::{fan_out, from_ref_blocking_mut, RefProjectionMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn stats(values: &[u32]) -> (usize, u32) {
+///     pin!(fan_out((
+///         from_ref_blocking_mut(|values: &[u32]| values.len()),
+///         from_ref_blocking_mut(|values: &[u32]| values.iter().sum()),
+///     )))
+///     .project_ref(values)
+///     .await
+/// }
+///
+/// assert_eq!(block_on(stats(&[1, 2, 3])), (3, 6));
+/// ```
+#[pin_project(!Unpin)]
+pub struct FanOut<T, A: ?Sized, B>
+where
+	T: Join<A, B>,
+{
+	// Declared before `projections` so that a leaked handle is dropped first.
+	state: UnsafeCell<FanOutState<T::Slots>>,
+	#[pin]
+	projections: T,
+	_phantom: PhantomData<fn(&A) -> B>,
+}
+
+enum FanOutState<S> {
+	Idle,
+	Running(S),
+	Done,
+}
+
+impl<T, A: ?Sized, B> FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	#[must_use]
+	pub fn new(projections: T) -> Self {
+		Self {
+			state: FanOutState::Idle.into(),
+			projections,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+// region: threading
+/// The erased handles in `state` only borrow `projections` and the borrowed input.
+unsafe impl<T, A: ?Sized, B> Send for FanOut<T, A, B>
+where
+	T: Send + Join<A, B>,
+	A: Sync,
+	B: Send,
+{
+}
+/// Through [`&FanOut`](`FanOut`), only `state`'s variant is read, so neither the [`Join::Slots`] nor the projections in `T` are shared.
+unsafe impl<T, A: ?Sized, B> Sync for FanOut<T, A, B> where T: Join<A, B> {}
+// endregion
+// region: projection impls
+impl<T, A: ?Sized, B> IntoRefProjectionMut<A, B, Self> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	type IntoRefProjMut = Self;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		self
+	}
+}
+
+impl<T, A: ?Sized, B> IntoMutProjectionMut<A, B, Self> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	type IntoMutProjMut = Self;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		self
+	}
+}
+
+impl<T, A: ?Sized, B> IntoFusedRefProjectionMut<A, B, Self> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	type IntoFusedRefProjMut = Self;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		self
+	}
+}
+
+impl<T, A: ?Sized, B> IntoFusedMutProjectionMut<A, B, Self> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	type IntoFusedMutProjMut = Self;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		self
+	}
+}
+
+impl<T, A: ?Sized, B> IntoProjectionMut<&A, B, Self> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<T, A: ?Sized, B> IntoProjectionMut<&mut A, B, Self> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<T, A: ?Sized, B> IntoFusedProjectionMut<&A, B, Self> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<T, A: ?Sized, B> IntoFusedProjectionMut<&mut A, B, Self> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<T, A: ?Sized, B> PollProjectionMut<&A, B> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &A) {
+		let this = self.project();
+		let state = this.state.get_mut();
+		*state = FanOutState::Idle;
+		// Safety: Dropped by `.clear()` or when `self` is dropped.
+		*state = FanOutState::Running(this.projections.start(value));
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		let state = self.project().state.get_mut();
+		let output = match state {
+			FanOutState::Running(slots) => match T::poll_join(slots, cx) {
+				Poll::Ready(output) => output,
+				Poll::Pending => return Poll::Pending,
+			},
+			FanOutState::Idle | FanOutState::Done => {
+				panic!("`FanOut::poll_result` called without a projection in progress")
+			}
+		};
+		// Also drops any projections that are still running after an error.
+		*state = FanOutState::Done;
+		Poll::Ready(output)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		*self.project().state.get_mut() = FanOutState::Idle;
+	}
+}
+
+impl<T, A: ?Sized, B> PollProjectionMut<&mut A, B> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &mut A) {
+		PollProjectionMut::<&A, B>::start(self, value);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		PollProjectionMut::<&A, B>::poll_result(self, cx)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		PollProjectionMut::<&A, B>::clear(self);
+	}
+}
+
+impl<T, A: ?Sized, B> FusedPollProjectionMut<&A, B> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	fn is_terminated(&self) -> bool {
+		!matches!(unsafe { &*self.state.get() }, FanOutState::Running(_))
+	}
+}
+
+impl<T, A: ?Sized, B> FusedPollProjectionMut<&mut A, B> for FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	fn is_terminated(&self) -> bool {
+		FusedPollProjectionMut::<&A, B>::is_terminated(self)
+	}
+}
+// endregion
+// region: conversions
+impl<T, A: ?Sized, B> IntoRefProjectionMut<A, B, FanOut<T, A, B>> for T
+where
+	T: Join<A, B>,
+{
+	type IntoRefProjMut = FanOut<T, A, B>;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		FanOut::new(self)
+	}
+}
+
+impl<T, A: ?Sized, B> IntoMutProjectionMut<A, B, FanOut<T, A, B>> for T
+where
+	T: Join<A, B>,
+{
+	type IntoMutProjMut = FanOut<T, A, B>;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		FanOut::new(self)
+	}
+}
+
+impl<T, A: ?Sized, B> IntoFusedRefProjectionMut<A, B, FanOut<T, A, B>> for T
+where
+	T: Join<A, B>,
+{
+	type IntoFusedRefProjMut = FanOut<T, A, B>;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		FanOut::new(self)
+	}
+}
+
+impl<T, A: ?Sized, B> IntoFusedMutProjectionMut<A, B, FanOut<T, A, B>> for T
+where
+	T: Join<A, B>,
+{
+	type IntoFusedMutProjMut = FanOut<T, A, B>;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		FanOut::new(self)
+	}
+}
+
+impl<T, A: ?Sized, B> IntoProjectionMut<&A, B, FanOut<T, A, B>> for T
+where
+	T: Join<A, B>,
+{
+	type IntoProjMut = FanOut<T, A, B>;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		FanOut::new(self)
+	}
+}
+
+impl<T, A: ?Sized, B> IntoProjectionMut<&mut A, B, FanOut<T, A, B>> for T
+where
+	T: Join<A, B>,
+{
+	type IntoProjMut = FanOut<T, A, B>;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		FanOut::new(self)
+	}
+}
+
+impl<T, A: ?Sized, B> IntoFusedProjectionMut<&A, B, FanOut<T, A, B>> for T
+where
+	T: Join<A, B>,
+{
+	type IntoFusedProjMut = FanOut<T, A, B>;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		FanOut::new(self)
+	}
+}
+
+impl<T, A: ?Sized, B> IntoFusedProjectionMut<&mut A, B, FanOut<T, A, B>> for T
+where
+	T: Join<A, B>,
+{
+	type IntoFusedProjMut = FanOut<T, A, B>;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		FanOut::new(self)
+	}
+}
+
+/// Tuple of [`RefProjectionMut<A, Bi>`] → [`FusedRefProjectionMut<A, (B1, B2, …)>`](`super::FusedRefProjectionMut`)
+#[must_use]
+pub fn fan_out<T, A: ?Sized, B>(projections: T) -> FanOut<T, A, B>
+where
+	T: Join<A, B>,
+{
+	FanOut::new(projections)
+}
+
+/// Tuple of [`RefProjectionMut<A, Result<Bi, E>>`](`RefProjectionMut`)
+/// → [`FusedRefProjectionMut<A, Result<(B1, B2, …), E>>`](`super::FusedRefProjectionMut`)
+///
+/// The first [`Err`] completes the projection immediately, dropping the remaining futures.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::projection::{from_ref_blocking_mut, try_fan_out, RefProjectionMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn parse(text: &str) -> Result<(u8, i8), core::num::ParseIntError> {
+///     pin!(try_fan_out((
+///         from_ref_blocking_mut(|text: &str| text.parse::<u8>()),
+///         from_ref_blocking_mut(|text: &str| text.parse::<i8>()),
+///     )))
+///     .project_ref(text)
+///     .await
+/// }
+///
+/// assert_eq!(block_on(parse("1")), Ok((1, 1)));
+/// assert!(block_on(parse("-1")).is_err());
+/// ```
+#[must_use]
+pub fn try_fan_out<T, A: ?Sized, B, E>(projections: T) -> FanOut<T, A, Result<B, E>>
+where
+	T: Join<A, Result<B, E>>,
+{
+	FanOut::new(projections)
+}
+// endregion
@@ -0,0 +1,183 @@
+use super::{
+	FusedPollProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
+	IntoFusedRefProjectionMut, IntoMutProjectionMut, IntoProjectionMut, IntoRefProjectionMut,
+	MutProjectionMut, PollProjectionMut, ProjectionMut, RefProjectionMut,
+};
+use crate::handles::ErasedFutureHandleMut;
+use core::{
+	cell::UnsafeCell,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use pin_project::pin_project;
+
+/// [`ProjectionMut<A, B>`] → [`FusedPollProjectionMut<A, B>`] (and so [`FusedProjectionMut<A, B>`](`super::FusedProjectionMut`))
+///
+/// Drives the future returned by the wrapped projection and drops it once it completes,
+/// so `P` doesn't have to be fused.
+///
+/// This also lifts projections that only implement [`ProjectionMut`] into the poll-based traits,
+/// which [`Either`](`super::Either`) requires of its variants.
+/// As `Fuse` isn't generic over `A`, the reference forms ([`RefProjectionMut`] and [`MutProjectionMut`]) carry over.
+///
+/// # Example
+///
+/// ```
+/// use core::{future::Future, pin::Pin};
+/// use ergo_pin::ergo_pin;
+/// use percolate::{
+///     handles::PinHandleMut,
+///     projection::{from_blocking_mut, Either, Fuse, ProjectionMut},
+/// };
+/// use pollster::block_on;
+///
+/// /// Implements only `ProjectionMut`, as projections outside this crate might.
+/// struct Offset<P>(P, i32);
+/// impl<P: ProjectionMut<i32, i32>> ProjectionMut<i32, i32> for Offset<P> {
+///     fn project(self: Pin<&mut Self>, value: i32) -> PinHandleMut<'_, dyn '_ + Future<Output = i32>> {
+///         let this = unsafe { self.get_unchecked_mut() };
+///         unsafe { Pin::new_unchecked(&mut this.0) }.project(value + this.1)
+///     }
+/// }
+///
+/// #[ergo_pin]
+/// async fn project(offset: bool, value: i32) -> i32 {
+///     let projection = if offset {
+///         Either::Left(Fuse::new(Offset(from_blocking_mut(|x: i32| x), 1)))
+///     } else {
+///         Either::Right(from_blocking_mut(|x: i32| -x))
+///     };
+///     pin!(projection).project(value).await
+/// }
+///
+/// assert_eq!(block_on(project(true, 1)), 2);
+/// assert_eq!(block_on(project(false, 1)), -1);
+/// ```
+#[pin_project(!Unpin)]
+pub struct Fuse<P, B> {
+	// Declared before `projection` so that a leaked handle is dropped first.
+	state: UnsafeCell<Option<ErasedFutureHandleMut<B>>>,
+	#[pin]
+	projection: P,
+}
+
+impl<P, B> Fuse<P, B> {
+	#[must_use]
+	pub fn new(projection: P) -> Self {
+		Self {
+			state: None.into(),
+			projection,
+		}
+	}
+}
+
+// region: threading
+/// The erased handle in `state` only borrows `projection`.
+unsafe impl<P, B> Send for Fuse<P, B>
+where
+	P: Send,
+	B: Send,
+{
+}
+/// [`&Fuse`](`Fuse`) only reads whether `state` holds a handle. Neither that handle nor `projection` is reachable through it, so this holds for any `P` and `B`.
+unsafe impl<P, B> Sync for Fuse<P, B> {}
+// endregion
+// region: projection impls
+impl<P, A, B> IntoProjectionMut<A, B, Self> for Fuse<P, B>
+where
+	P: ProjectionMut<A, B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, A, B> IntoFusedProjectionMut<A, B, Self> for Fuse<P, B>
+where
+	P: ProjectionMut<A, B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoRefProjectionMut<A, B, Self> for Fuse<P, B>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoRefProjMut = Self;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoMutProjectionMut<A, B, Self> for Fuse<P, B>
+where
+	P: MutProjectionMut<A, B>,
+{
+	type IntoMutProjMut = Self;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoFusedRefProjectionMut<A, B, Self> for Fuse<P, B>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoFusedRefProjMut = Self;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoFusedMutProjectionMut<A, B, Self> for Fuse<P, B>
+where
+	P: MutProjectionMut<A, B>,
+{
+	type IntoFusedMutProjMut = Self;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		self
+	}
+}
+
+impl<P, A, B> PollProjectionMut<A, B> for Fuse<P, B>
+where
+	P: ProjectionMut<A, B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: A) {
+		let this = self.project();
+		let state = this.state.get_mut();
+		*state = None;
+		// Safety: Dropped by `.clear()`, on completion or when `self` is dropped.
+		*state = Some(ErasedFutureHandleMut::new(this.projection.project(value)));
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		let state = self.project().state.get_mut();
+		let output = state
+			.as_mut()
+			.expect("`Fuse::poll_result` called without a projection in progress")
+			.poll(cx);
+		if output.is_ready() {
+			*state = None;
+		}
+		output
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		*self.project().state.get_mut() = None;
+	}
+}
+
+impl<P, A, B> FusedPollProjectionMut<A, B> for Fuse<P, B>
+where
+	P: ProjectionMut<A, B>,
+{
+	fn is_terminated(&self) -> bool {
+		unsafe { &*self.state.get() }.is_none()
+	}
+}
+// endregion
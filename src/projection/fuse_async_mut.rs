@@ -0,0 +1,130 @@
+use super::{FusedPollProjectionMut, IntoFusedProjectionMut, IntoProjectionMut, PollProjectionMut};
+use core::{
+	future::Future,
+	marker::PhantomData,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use pin_project::pin_project;
+
+/// [`From<`](`From`)[`P: FnMut(A) -> `](`FnMut`)`F: `[`Future<Output = B>`](`Future`)[`>`](`FnMut`)[`>`](`From`)
+/// and [`FusedProjectionMut<A, B>`](`super::FusedProjectionMut`)
+///
+/// Unlike [`AsyncMut`](`super::AsyncMut`), this drops the future once it completes and tracks that itself,
+/// so `F` doesn't have to be a [`FusedFuture`](`futures_core::FusedFuture`) (which `async` blocks aren't).
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::projection::{fuse_async_mut, FusedProjectionMut, IntoFusedProjectionMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn project_fused<A, B, X>(value: A, projection: impl IntoFusedProjectionMut<A, B, X>) -> B {
+///     pin!(projection.into_fused_projection_mut())
+///         .project_fused(value)
+///         .await
+/// }
+///
+/// assert_eq!(block_on(project_fused(1, fuse_async_mut(|x: u8| async move { x + 1 }))), 2);
+/// ```
+#[pin_project]
+pub struct FuseAsyncMut<P, A, F, B>
+where
+	P: FnMut(A) -> F,
+	F: Future<Output = B>,
+{
+	projection: P,
+	#[pin]
+	future: Option<F>,
+	_phantom: PhantomData<fn(A)>,
+}
+
+// region: projection impls
+impl<P, A, F, B> IntoProjectionMut<A, B, Self> for FuseAsyncMut<P, A, F, B>
+where
+	P: FnMut(A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, A, F, B> IntoFusedProjectionMut<A, B, Self> for FuseAsyncMut<P, A, F, B>
+where
+	P: FnMut(A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, A, F, B> PollProjectionMut<A, B> for FuseAsyncMut<P, A, F, B>
+where
+	P: FnMut(A) -> F,
+	F: Future<Output = B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: A) {
+		let mut this = self.project();
+		let future = (this.projection)(value);
+		this.future.set(Some(future));
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		let mut future = self.project().future;
+		let output = future
+			.as_mut()
+			.as_pin_mut()
+			.expect("`FuseAsyncMut::poll_result` called without a projection in progress")
+			.poll(cx);
+		if output.is_ready() {
+			future.set(None);
+		}
+		output
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		self.project().future.set(None);
+	}
+}
+
+impl<P, A, F, B> FusedPollProjectionMut<A, B> for FuseAsyncMut<P, A, F, B>
+where
+	P: FnMut(A) -> F,
+	F: Future<Output = B>,
+{
+	fn is_terminated(&self) -> bool {
+		self.future.is_none()
+	}
+}
+// endregion
+// region: conversions
+impl<P, A, F, B> From<P> for FuseAsyncMut<P, A, F, B>
+where
+	P: FnMut(A) -> F,
+	F: Future<Output = B>,
+{
+	fn from(projection: P) -> Self {
+		Self {
+			projection,
+			future: None,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+/// [`FnMut(A) -> `](`FnMut`)[`Future<Output = B>`](`Future`) → [`FusedProjectionMut<A, B>`](`super::FusedProjectionMut`)
+#[must_use]
+pub fn fuse_async_mut<P, A, F, B>(projection: P) -> FuseAsyncMut<P, A, F, B>
+where
+	P: FnMut(A) -> F,
+	F: Future<Output = B>,
+{
+	projection.into()
+}
+// endregion
@@ -0,0 +1,233 @@
+use super::{
+	FusedPollProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
+	IntoFusedRefProjectionMut, IntoMutProjectionMut, IntoProjectionMut, IntoRefProjectionMut,
+	PollProjectionMut,
+};
+use core::{
+	future::Future,
+	marker::PhantomData,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use pin_project::pin_project;
+
+/// [`From<`](`From`)[`P: FnMut(&A) -> `](`FnMut`)`F: `[`Future<Output = B>`](`Future`)[`>`](`FnMut`)[`>`](`From`)
+/// and [`FusedRefProjectionMut<A, B>`](`super::FusedRefProjectionMut`)
+///
+/// Like [`FuseAsyncMut`](`super::FuseAsyncMut`), but projects from references.
+/// `F` can't borrow the reference, so copy what's needed out of it before the `async` block.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::{
+///     predicate::{FusedPredicateMut, IntoFusedPredicateMut},
+///     projection::fuse_ref_async_mut,
+/// };
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn test_fused<T, X>(value: &T, predicate: impl IntoFusedPredicateMut<T, X>) -> bool {
+///     FusedPredicateMut::test(pin!(predicate.into_fused_predicate_mut()), value).await
+/// }
+///
+/// let is_even = fuse_ref_async_mut(|x: &u8| {
+///     let x = *x;
+///     async move { x % 2 == 0 }
+/// });
+/// assert!(block_on(test_fused(&2, is_even)));
+/// ```
+#[pin_project]
+pub struct FuseRefAsyncMut<P, A: ?Sized, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	projection: P,
+	#[pin]
+	future: Option<F>,
+	_phantom: PhantomData<fn(&A)>,
+}
+
+// region: projection impls
+impl<P, A: ?Sized, F, B> IntoRefProjectionMut<A, B, Self> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoRefProjMut = Self;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, F, B> IntoMutProjectionMut<A, B, Self> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoMutProjMut = Self;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, F, B> IntoFusedRefProjectionMut<A, B, Self> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoFusedRefProjMut = Self;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, F, B> IntoFusedMutProjectionMut<A, B, Self> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoFusedMutProjMut = Self;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, F, B> IntoProjectionMut<&A, B, Self> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, F, B> IntoProjectionMut<&mut A, B, Self> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, F, B> IntoFusedProjectionMut<&A, B, Self> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, F, B> IntoFusedProjectionMut<&mut A, B, Self> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, F, B> PollProjectionMut<&A, B> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &A) {
+		let mut this = self.project();
+		let future = (this.projection)(value);
+		this.future.set(Some(future));
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		let mut future = self.project().future;
+		let output = future
+			.as_mut()
+			.as_pin_mut()
+			.expect("`FuseRefAsyncMut::poll_result` called without a projection in progress")
+			.poll(cx);
+		if output.is_ready() {
+			future.set(None);
+		}
+		output
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		self.project().future.set(None);
+	}
+}
+
+impl<P, A: ?Sized, F, B> PollProjectionMut<&mut A, B> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &mut A) {
+		PollProjectionMut::<&A, B>::start(self, value);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		PollProjectionMut::<&A, B>::poll_result(self, cx)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		PollProjectionMut::<&A, B>::clear(self);
+	}
+}
+
+impl<P, A: ?Sized, F, B> FusedPollProjectionMut<&A, B> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	fn is_terminated(&self) -> bool {
+		self.future.is_none()
+	}
+}
+
+impl<P, A: ?Sized, F, B> FusedPollProjectionMut<&mut A, B> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	fn is_terminated(&self) -> bool {
+		self.future.is_none()
+	}
+}
+// endregion
+// region: conversions
+impl<P, A: ?Sized, F, B> From<P> for FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	fn from(projection: P) -> Self {
+		Self {
+			projection,
+			future: None,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+/// [`FnMut(&A) -> `](`FnMut`)[`Future<Output = B>`](`Future`) → [`FusedRefProjectionMut<A, B>`](`super::FusedRefProjectionMut`)
+#[must_use]
+pub fn fuse_ref_async_mut<P, A: ?Sized, F, B>(projection: P) -> FuseRefAsyncMut<P, A, F, B>
+where
+	P: FnMut(&A) -> F,
+	F: Future<Output = B>,
+{
+	projection.into()
+}
+// endregion
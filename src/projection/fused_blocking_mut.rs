@@ -1,17 +1,14 @@
-use super::{FusedProjectionMut, IntoFusedProjectionMut, IntoProjectionMut, ProjectionMut};
-use crate::handles::{PinHandleMut, RunOnce, Runnable};
+use super::{FusedPollProjectionMut, IntoFusedProjectionMut, IntoProjectionMut, PollProjectionMut};
 use core::{
 	cell::UnsafeCell,
-	mem::transmute,
 	pin::Pin,
 	task::{Context, Poll},
 };
-use futures_core::{FusedFuture, Future};
 use pin_project::pin_project;
 use tap::Pipe;
 
 /// [`From<`](`From`)[`P: FnMut(A) -> B>`](`FnMut`)[`>`](`From`)
-/// and [`FusedProjectionMut<A, B>`]
+/// and [`FusedProjectionMut<A, B>`](`super::FusedProjectionMut`)
 #[pin_project]
 pub struct FusedBlockingMut<P, A, B>
 where
@@ -28,7 +25,7 @@ where
 	A: Send,
 {
 }
-/// [`&dyn FusedBlockingMut`] is immutable and doesn't allow access to stored data.
+/// [`&dyn FusedBlockingMut`] is immutable and only exposes whether a parameter is stored, not the parameter itself.
 unsafe impl<P, A, B> Sync for FusedBlockingMut<P, A, B> where P: FnMut(A) -> B {}
 // endregion
 // region: projection impls
@@ -52,89 +49,33 @@ where
 	}
 }
 
-impl<P, A, B> ProjectionMut<A, B> for FusedBlockingMut<P, A, B>
+impl<P, A, B> PollProjectionMut<A, B> for FusedBlockingMut<P, A, B>
 where
 	P: FnMut(A) -> B,
 {
-	#[must_use]
-	fn project(self: Pin<&mut Self>, value: A) -> PinHandleMut<'_, dyn '_ + Future<Output = B>> {
-		unsafe { *self.param.get() = Some(value) };
-		let this = self.into_ref();
-		PinHandleMut::new(
-			unsafe { transmute::<Pin<&Self>, Pin<&mut FusedBlockingMutFuture<P, A, B>>>(this) },
-			Some(unsafe {
-				RunOnce::new(transmute::<Pin<&Self>, &ClearFusedBlockingMut<P, A, B>>(
-					this,
-				))
-			}),
-		)
+	unsafe fn start(self: Pin<&mut Self>, value: A) {
+		*self.param.get() = Some(value);
 	}
-}
-
-impl<P, A, B> FusedProjectionMut<A, B> for FusedBlockingMut<P, A, B>
-where
-	P: FnMut(A) -> B,
-{
-	fn project_fused(
-		self: Pin<&mut Self>,
-		value: A,
-	) -> PinHandleMut<'_, dyn '_ + FusedFuture<Output = B>> {
-		unsafe { *self.param.get() = Some(value) };
-		let this = self.into_ref();
-		PinHandleMut::new(
-			unsafe { transmute::<Pin<&Self>, Pin<&mut FusedBlockingMutFuture<P, A, B>>>(this) },
-			Some(unsafe {
-				RunOnce::new(transmute::<Pin<&Self>, &ClearFusedBlockingMut<P, A, B>>(
-					this,
-				))
-			}),
-		)
-	}
-}
-// endregion
-// region: future
-#[repr(transparent)]
-#[pin_project]
-struct FusedBlockingMutFuture<P, A, B>(#[pin] UnsafeCell<FusedBlockingMut<P, A, B>>)
-where
-	P: FnMut(A) -> B;
 
-impl<P, A, B> Future for FusedBlockingMutFuture<P, A, B>
-where
-	P: FnMut(A) -> B,
-{
-	type Output = B;
-	fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
-		let blocking = unsafe { Pin::new_unchecked(&*self.project().0.get()) };
-		unsafe { &mut *blocking.param.get() }
+	fn poll_result(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<B> {
+		unsafe { &mut *self.param.get() }
 			.take()
-			.expect("`FusedBlockingMutFuture::poll` called twice")
-			.pipe(|param| unsafe { &mut *blocking.projection.get() }(param))
+			.expect("`FusedBlockingMut::poll_result` called without a projection in progress")
+			.pipe(|param| unsafe { &mut *self.projection.get() }(param))
 			.pipe(Poll::Ready)
 	}
-}
 
-impl<P, A, B> FusedFuture for FusedBlockingMutFuture<P, A, B>
-where
-	P: FnMut(A) -> B,
-{
-	fn is_terminated(&self) -> bool {
-		unsafe { &*(*self.0.get()).param.get() }.is_none()
+	fn clear(self: Pin<&mut Self>) {
+		unsafe { &mut *self.param.get() }.take().pipe(drop);
 	}
 }
-// endregion
-// region: clear
-#[repr(transparent)]
-#[pin_project]
-struct ClearFusedBlockingMut<P, A, B>(#[pin] FusedBlockingMut<P, A, B>)
-where
-	P: FnMut(A) -> B;
-impl<P, A, B> Runnable<(), ()> for ClearFusedBlockingMut<P, A, B>
+
+impl<P, A, B> FusedPollProjectionMut<A, B> for FusedBlockingMut<P, A, B>
 where
 	P: FnMut(A) -> B,
 {
-	fn run(&self, _: ()) {
-		unsafe { &mut *self.0.param.get() }.take().pipe(drop)
+	fn is_terminated(&self) -> bool {
+		unsafe { &*self.param.get() }.is_none()
 	}
 }
 // endregion
@@ -171,7 +112,7 @@ where
 	}
 }
 
-/// [`FnMut(A) -> B`](`FnMut`) → [`FusedProjectionMut<A, B>`]
+/// [`FnMut(A) -> B`](`FnMut`) → [`FusedProjectionMut<A, B>`](`super::FusedProjectionMut`)
 #[must_use]
 pub fn from_blocking_mut<P, A, B>(projection: P) -> FusedBlockingMut<P, A, B>
 where
@@ -1,15 +1,12 @@
 use super::{
-	FusedProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut, IntoMutProjectionMut,
-	IntoProjectionMut, ProjectionMut,
+	FusedPollProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
+	IntoMutProjectionMut, IntoProjectionMut, PollProjectionMut,
 };
-use crate::handles::PinHandleMut;
 use core::{
-	mem::transmute,
 	pin::Pin,
 	ptr::NonNull,
 	task::{Context, Poll},
 };
-use futures_core::{FusedFuture, Future};
 use pin_project::pin_project;
 use tap::Pipe;
 
@@ -28,7 +25,7 @@ where
 unsafe impl<P, A: ?Sized, B> Send for FusedMutBlockingMut<P, A, B>
 where
 	P: Send + FnMut(&mut A) -> B,
-	A: Sync,
+	A: Send,
 {
 }
 /// [`&dyn MutBlockingMut`] is immutable.
@@ -55,7 +52,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoProjectionMut<&'a mut A, B, Self> for FusedMutBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> IntoProjectionMut<&mut A, B, Self> for FusedMutBlockingMut<P, A, B>
 where
 	P: FnMut(&mut A) -> B,
 {
@@ -65,8 +62,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoFusedProjectionMut<&'a mut A, B, Self>
-	for FusedMutBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> IntoFusedProjectionMut<&mut A, B, Self> for FusedMutBlockingMut<P, A, B>
 where
 	P: FnMut(&mut A) -> B,
 {
@@ -76,73 +72,35 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> ProjectionMut<&'a mut A, B> for FusedMutBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> PollProjectionMut<&mut A, B> for FusedMutBlockingMut<P, A, B>
 where
 	P: FnMut(&mut A) -> B,
 {
-	#[must_use]
-	fn project(
-		mut self: Pin<&mut Self>,
-		value: &mut A,
-	) -> PinHandleMut<'_, dyn '_ + Future<Output = B>> {
+	unsafe fn start(mut self: Pin<&mut Self>, value: &mut A) {
 		self.param = Some(value.into());
-		PinHandleMut::new(
-			unsafe {
-				transmute::<Pin<&mut Self>, Pin<&mut FusedMutBlockingFutureMut<P, A, B>>>(self)
-			},
-			None,
-		)
 	}
-}
-
-impl<'a, P, A: ?Sized, B> FusedProjectionMut<&'a mut A, B> for FusedMutBlockingMut<P, A, B>
-where
-	P: FnMut(&mut A) -> B,
-{
-	#[must_use]
-	fn project_fused(
-		mut self: Pin<&mut Self>,
-		value: &mut A,
-	) -> PinHandleMut<'_, dyn '_ + FusedFuture<Output = B>> {
-		self.param = Some(value.into());
-		PinHandleMut::new(
-			unsafe {
-				transmute::<Pin<&mut Self>, Pin<&mut FusedMutBlockingFutureMut<P, A, B>>>(self)
-			},
-			None,
-		)
-	}
-}
-// endregion
-// region: future
-#[repr(transparent)]
-#[pin_project]
-struct FusedMutBlockingFutureMut<P, A: ?Sized, B>(#[pin] FusedMutBlockingMut<P, A, B>)
-where
-	P: FnMut(&mut A) -> B;
 
-impl<P, A: ?Sized, B> Future for FusedMutBlockingFutureMut<P, A, B>
-where
-	P: FnMut(&mut A) -> B,
-{
-	type Output = B;
-	fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
-		let blocking = &mut self.project().0;
+	fn poll_result(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<B> {
+		let blocking = self.project();
 		blocking
 			.param
 			.take()
-			.expect("`MutBlockingFutureMut::poll` called twice")
+			.expect("`FusedMutBlockingMut::poll_result` called without a projection in progress")
 			.pipe(|mut param_ptr| (blocking.projection)(unsafe { param_ptr.as_mut() }))
 			.pipe(Poll::Ready)
 	}
+
+	fn clear(mut self: Pin<&mut Self>) {
+		self.param = None;
+	}
 }
 
-impl<P, A: ?Sized, B> FusedFuture for FusedMutBlockingFutureMut<P, A, B>
+impl<P, A: ?Sized, B> FusedPollProjectionMut<&mut A, B> for FusedMutBlockingMut<P, A, B>
 where
 	P: FnMut(&mut A) -> B,
 {
 	fn is_terminated(&self) -> bool {
-		self.0.param.is_none()
+		self.param.is_none()
 	}
 }
 // endregion
@@ -179,7 +137,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoProjectionMut<&'a mut A, B, FusedMutBlockingMut<P, A, B>> for P
+impl<P, A: ?Sized, B> IntoProjectionMut<&mut A, B, FusedMutBlockingMut<P, A, B>> for P
 where
 	P: FnMut(&mut A) -> B,
 {
@@ -189,7 +147,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoFusedProjectionMut<&'a mut A, B, FusedMutBlockingMut<P, A, B>> for P
+impl<P, A: ?Sized, B> IntoFusedProjectionMut<&mut A, B, FusedMutBlockingMut<P, A, B>> for P
 where
 	P: FnMut(&mut A) -> B,
 {
@@ -1,16 +1,13 @@
 use super::{
-	FusedProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
+	FusedPollProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
 	IntoFusedRefProjectionMut, IntoMutProjectionMut, IntoProjectionMut, IntoRefProjectionMut,
-	ProjectionMut,
+	PollProjectionMut,
 };
-use crate::handles::PinHandleMut;
 use core::{
-	mem::transmute,
 	pin::Pin,
 	ptr::NonNull,
 	task::{Context, Poll},
 };
-use futures_core::{FusedFuture, Future};
 use pin_project::pin_project;
 use tap::Pipe;
 
@@ -76,7 +73,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoProjectionMut<&'a A, B, Self> for FusedRefBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> IntoProjectionMut<&A, B, Self> for FusedRefBlockingMut<P, A, B>
 where
 	P: FnMut(&A) -> B,
 {
@@ -86,7 +83,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoProjectionMut<&'a mut A, B, Self> for FusedRefBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> IntoProjectionMut<&mut A, B, Self> for FusedRefBlockingMut<P, A, B>
 where
 	P: FnMut(&A) -> B,
 {
@@ -96,7 +93,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoFusedProjectionMut<&'a A, B, Self> for FusedRefBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> IntoFusedProjectionMut<&A, B, Self> for FusedRefBlockingMut<P, A, B>
 where
 	P: FnMut(&A) -> B,
 {
@@ -106,8 +103,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoFusedProjectionMut<&'a mut A, B, Self>
-	for FusedRefBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> IntoFusedProjectionMut<&mut A, B, Self> for FusedRefBlockingMut<P, A, B>
 where
 	P: FnMut(&A) -> B,
 {
@@ -117,111 +113,61 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> ProjectionMut<&'a A, B> for FusedRefBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> PollProjectionMut<&A, B> for FusedRefBlockingMut<P, A, B>
 where
 	P: FnMut(&A) -> B,
 {
-	#[must_use]
-	fn project(
-		mut self: Pin<&mut Self>,
-		value: &A,
-	) -> PinHandleMut<'_, dyn '_ + Future<Output = B>> {
+	unsafe fn start(mut self: Pin<&mut Self>, value: &A) {
 		self.param = Some(value.into());
-		PinHandleMut::new(
-			unsafe {
-				transmute::<Pin<&mut Self>, Pin<&mut FusedRefBlockingFutureMut<P, A, B>>>(self)
-			},
-			None,
-		)
 	}
-}
 
-impl<'a, P, A: ?Sized, B> ProjectionMut<&'a mut A, B> for FusedRefBlockingMut<P, A, B>
-where
-	P: FnMut(&A) -> B,
-{
-	#[must_use]
-	fn project(
-		mut self: Pin<&mut Self>,
-		value: &mut A,
-	) -> PinHandleMut<'_, dyn '_ + Future<Output = B>> {
-		self.param = Some(value.into());
-		PinHandleMut::new(
-			unsafe {
-				transmute::<Pin<&mut Self>, Pin<&mut FusedRefBlockingFutureMut<P, A, B>>>(self)
-			},
-			None,
-		)
+	fn poll_result(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<B> {
+		let blocking = self.project();
+		blocking
+			.param
+			.take()
+			.expect("`FusedRefBlockingMut::poll_result` called without a projection in progress")
+			.pipe(|param_ptr| (blocking.projection)(unsafe { param_ptr.as_ref() }))
+			.pipe(Poll::Ready)
 	}
-}
 
-impl<'a, P, A: ?Sized, B> FusedProjectionMut<&'a A, B> for FusedRefBlockingMut<P, A, B>
-where
-	P: FnMut(&A) -> B,
-{
-	#[must_use]
-	fn project_fused(
-		mut self: Pin<&mut Self>,
-		value: &A,
-	) -> PinHandleMut<'_, dyn '_ + FusedFuture<Output = B>> {
-		self.param = Some(value.into());
-		PinHandleMut::new(
-			unsafe {
-				transmute::<Pin<&mut Self>, Pin<&mut FusedRefBlockingFutureMut<P, A, B>>>(self)
-			},
-			None,
-		)
+	fn clear(mut self: Pin<&mut Self>) {
+		self.param = None;
 	}
 }
 
-impl<'a, P, A: ?Sized, B> FusedProjectionMut<&'a mut A, B> for FusedRefBlockingMut<P, A, B>
+impl<P, A: ?Sized, B> PollProjectionMut<&mut A, B> for FusedRefBlockingMut<P, A, B>
 where
 	P: FnMut(&A) -> B,
 {
-	#[must_use]
-	fn project_fused(
-		mut self: Pin<&mut Self>,
-		value: &mut A,
-	) -> PinHandleMut<'_, dyn '_ + FusedFuture<Output = B>> {
-		self.param = Some(value.into());
-		PinHandleMut::new(
-			unsafe {
-				transmute::<Pin<&mut Self>, Pin<&mut FusedRefBlockingFutureMut<P, A, B>>>(self)
-			},
-			None,
-		)
+	unsafe fn start(self: Pin<&mut Self>, value: &mut A) {
+		PollProjectionMut::<&A, B>::start(self, value);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		PollProjectionMut::<&A, B>::poll_result(self, cx)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		PollProjectionMut::<&A, B>::clear(self);
 	}
 }
-// endregion
-// region: future
-#[repr(transparent)]
-#[pin_project]
-struct FusedRefBlockingFutureMut<P, A: ?Sized, B>(#[pin] FusedRefBlockingMut<P, A, B>)
-where
-	P: FnMut(&A) -> B;
 
-impl<P, A: ?Sized, B> Future for FusedRefBlockingFutureMut<P, A, B>
+impl<P, A: ?Sized, B> FusedPollProjectionMut<&A, B> for FusedRefBlockingMut<P, A, B>
 where
 	P: FnMut(&A) -> B,
 {
-	type Output = B;
-	fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
-		let blocking = &mut self.project().0;
-		blocking
-			.param
-			.take()
-			.expect("`RefBlockingFutureMut::poll` called twice")
-			.pipe(|param_ptr| (blocking.projection)(unsafe { param_ptr.as_ref() }))
-			.pipe(Poll::Ready)
+	fn is_terminated(&self) -> bool {
+		self.param.is_none()
 	}
 }
 
-impl<P, A: ?Sized, B> FusedFuture for FusedRefBlockingFutureMut<P, A, B>
+impl<P, A: ?Sized, B> FusedPollProjectionMut<&mut A, B> for FusedRefBlockingMut<P, A, B>
 where
 	P: FnMut(&A) -> B,
 {
 	fn is_terminated(&self) -> bool {
-		self.0.param.is_none()
+		self.param.is_none()
 	}
 }
 // endregion
@@ -278,7 +224,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoProjectionMut<&'a A, B, FusedRefBlockingMut<P, A, B>> for P
+impl<P, A: ?Sized, B> IntoProjectionMut<&A, B, FusedRefBlockingMut<P, A, B>> for P
 where
 	P: FnMut(&A) -> B,
 {
@@ -288,7 +234,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoProjectionMut<&'a mut A, B, FusedRefBlockingMut<P, A, B>> for P
+impl<P, A: ?Sized, B> IntoProjectionMut<&mut A, B, FusedRefBlockingMut<P, A, B>> for P
 where
 	P: FnMut(&A) -> B,
 {
@@ -298,7 +244,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoFusedProjectionMut<&'a A, B, FusedRefBlockingMut<P, A, B>> for P
+impl<P, A: ?Sized, B> IntoFusedProjectionMut<&A, B, FusedRefBlockingMut<P, A, B>> for P
 where
 	P: FnMut(&A) -> B,
 {
@@ -308,7 +254,7 @@ where
 	}
 }
 
-impl<'a, P, A: ?Sized, B> IntoFusedProjectionMut<&'a mut A, B, FusedRefBlockingMut<P, A, B>> for P
+impl<P, A: ?Sized, B> IntoFusedProjectionMut<&mut A, B, FusedRefBlockingMut<P, A, B>> for P
 where
 	P: FnMut(&A) -> B,
 {
@@ -0,0 +1,335 @@
+use super::{
+	FusedPollProjectionMut, IntoFusedProjectionMut, IntoProjectionMut, PollProjectionMut,
+	ProjectionMut,
+};
+use crate::handles::ErasedFutureHandleMut;
+use core::{
+	cell::UnsafeCell,
+	mem::{self, MaybeUninit},
+	pin::Pin,
+	ptr,
+	task::{Context, Poll},
+};
+use pin_project::pin_project;
+
+/// A key-value store that [`Memoize`] looks up previous outputs in.
+pub trait Cache<K, V> {
+	/// Retrieves the value cached for `key`, if any.
+	///
+	/// This takes `&mut self` so that implementations can track usage.
+	fn get(&mut self, key: &K) -> Option<&V>;
+
+	/// Caches `value` for `key`, possibly evicting other entries.
+	fn insert(&mut self, key: K, value: V);
+}
+
+/// A fixed-capacity least-recently-used [`Cache`] that doesn't allocate.
+///
+/// Lookups are linear in `CAPACITY`, so this is best suited to small caches.
+pub struct LruCache<K, V, const CAPACITY: usize> {
+	/// Most recently used first.
+	entries: [MaybeUninit<(K, V)>; CAPACITY],
+	len: usize,
+}
+
+impl<K, V, const CAPACITY: usize> LruCache<K, V, CAPACITY> {
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			entries: unsafe {
+				// Safety: An array of `MaybeUninit` doesn't need initialisation.
+				MaybeUninit::<[MaybeUninit<(K, V)>; CAPACITY]>::uninit().assume_init()
+			},
+			len: 0,
+		}
+	}
+
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	pub fn clear(&mut self) {
+		let len = mem::replace(&mut self.len, 0);
+		for entry in &mut self.entries[..len] {
+			unsafe { ptr::drop_in_place(entry.as_mut_ptr()) }
+		}
+	}
+
+	fn position(&self, key: &K) -> Option<usize>
+	where
+		K: Eq,
+	{
+		self.entries[..self.len]
+			.iter()
+			.position(|entry| unsafe { &(*entry.as_ptr()).0 } == key)
+	}
+}
+
+impl<K, V, const CAPACITY: usize> Default for LruCache<K, V, CAPACITY> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<K, V, const CAPACITY: usize> Drop for LruCache<K, V, CAPACITY> {
+	fn drop(&mut self) {
+		self.clear();
+	}
+}
+
+impl<K: Eq, V, const CAPACITY: usize> Cache<K, V> for LruCache<K, V, CAPACITY> {
+	fn get(&mut self, key: &K) -> Option<&V> {
+		let i = self.position(key)?;
+		self.entries[..=i].rotate_right(1);
+		Some(unsafe { &(*self.entries[0].as_ptr()).1 })
+	}
+
+	fn insert(&mut self, key: K, value: V) {
+		if CAPACITY == 0 {
+			return;
+		}
+		if let Some(i) = self.position(&key) {
+			// The old entry is only dropped once the new one took its place, in case that panics.
+			let old = mem::replace(&mut self.entries[i], MaybeUninit::new((key, value)));
+			self.entries[..=i].rotate_right(1);
+			drop(unsafe {
+				// Safety: Moved out of an initialised slot.
+				old.assume_init()
+			});
+			return;
+		}
+		if self.len == CAPACITY {
+			self.len -= 1;
+			unsafe { ptr::drop_in_place(self.entries[self.len].as_mut_ptr()) }
+		}
+		self.entries[self.len] = MaybeUninit::new((key, value));
+		self.len += 1;
+		self.entries[..self.len].rotate_right(1);
+	}
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Cache<K, V> for std::collections::HashMap<K, V, S>
+where
+	K: Eq + core::hash::Hash,
+	S: core::hash::BuildHasher,
+{
+	fn get(&mut self, key: &K) -> Option<&V> {
+		std::collections::HashMap::get(self, key)
+	}
+
+	fn insert(&mut self, key: K, value: V) {
+		std::collections::HashMap::insert(self, key, value);
+	}
+}
+
+/// [`ProjectionMut<A, B>`] and [`Cache<A, B>`] → [`FusedProjectionMut<A, B>`](`super::FusedProjectionMut`)
+///
+/// Outputs are cloned out of the cache for equal inputs, in which case `P` isn't invoked at all.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::projection::{memoize, LruCache, ProjectionMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn double_all(inputs: &[u32], calls: &mut usize) -> Vec<u32> {
+///     let mut doubled = pin!(memoize(
+///         |x: u32| {
+///             *calls += 1;
+///             x * 2
+///         },
+///         LruCache::<_, _, 2>::new(),
+///     ));
+///
+///     let mut outputs = vec![];
+///     for &x in inputs {
+///         outputs.push(doubled.as_mut().project(x).await);
+///     }
+///     outputs
+/// }
+///
+/// let mut calls = 0;
+/// assert_eq!(block_on(double_all(&[1, 2, 1, 3, 2], &mut calls)), [2, 4, 2, 6, 4]);
+/// assert_eq!(calls, 4); // `2` was evicted by `3`.
+/// ```
+#[pin_project(!Unpin)]
+pub struct Memoize<P, C, A, B>
+where
+	P: ProjectionMut<A, B>,
+	C: Cache<A, B>,
+	A: Clone,
+	B: Clone,
+{
+	// Declared before `projection` so that a leaked handle is dropped first.
+	state: UnsafeCell<MemoizeState<A, B>>,
+	cache: UnsafeCell<C>,
+	#[pin]
+	projection: P,
+}
+
+enum MemoizeState<A, B> {
+	Idle,
+	Hit(B),
+	Miss(A, ErasedFutureHandleMut<B>),
+	Done,
+}
+
+impl<P, C, A, B> Memoize<P, C, A, B>
+where
+	P: ProjectionMut<A, B>,
+	C: Cache<A, B>,
+	A: Clone,
+	B: Clone,
+{
+	#[must_use]
+	pub fn new(projection: P, cache: C) -> Self {
+		Self {
+			state: MemoizeState::Idle.into(),
+			cache: cache.into(),
+			projection,
+		}
+	}
+
+	#[must_use]
+	pub fn cache_mut(self: Pin<&mut Self>) -> &mut C {
+		self.project().cache.get_mut()
+	}
+}
+
+// region: threading
+/// The erased handle in `state` only borrows `projection`.
+unsafe impl<P, C, A, B> Send for Memoize<P, C, A, B>
+where
+	P: Send + ProjectionMut<A, B>,
+	C: Send + Cache<A, B>,
+	A: Send + Clone,
+	B: Send + Clone,
+{
+}
+/// [`&Memoize`](`Memoize`) only reads which stage `state` is in. The cache, pending key and cached output are only reached through [`Pin<&mut Memoize>`](`Pin`).
+unsafe impl<P, C, A, B> Sync for Memoize<P, C, A, B>
+where
+	P: ProjectionMut<A, B>,
+	C: Cache<A, B>,
+	A: Clone,
+	B: Clone,
+{
+}
+// endregion
+// region: projection impls
+impl<P, C, A, B> IntoProjectionMut<A, B, Self> for Memoize<P, C, A, B>
+where
+	P: ProjectionMut<A, B>,
+	C: Cache<A, B>,
+	A: Clone,
+	B: Clone,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, C, A, B> IntoFusedProjectionMut<A, B, Self> for Memoize<P, C, A, B>
+where
+	P: ProjectionMut<A, B>,
+	C: Cache<A, B>,
+	A: Clone,
+	B: Clone,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, C, A, B> PollProjectionMut<A, B> for Memoize<P, C, A, B>
+where
+	P: ProjectionMut<A, B>,
+	C: Cache<A, B>,
+	A: Clone,
+	B: Clone,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: A) {
+		let this = self.project();
+		let state = this.state.get_mut();
+		*state = MemoizeState::Idle;
+		*state = match this.cache.get_mut().get(&value) {
+			Some(cached) => MemoizeState::Hit(cached.clone()),
+			None => MemoizeState::Miss(
+				value.clone(),
+				// Safety: Dropped by `.clear()` or when `self` is dropped.
+				ErasedFutureHandleMut::new(this.projection.project(value)),
+			),
+		};
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		let this = self.project();
+		let state = this.state.get_mut();
+		match state {
+			MemoizeState::Hit(_) => match mem::replace(state, MemoizeState::Done) {
+				MemoizeState::Hit(cached) => Poll::Ready(cached),
+				_ => unreachable!(),
+			},
+			MemoizeState::Miss(_, future) => {
+				let output = match future.poll(cx) {
+					Poll::Ready(output) => output,
+					Poll::Pending => return Poll::Pending,
+				};
+				match mem::replace(state, MemoizeState::Done) {
+					MemoizeState::Miss(key, _) => {
+						this.cache.get_mut().insert(key, output.clone());
+					}
+					_ => unreachable!(),
+				}
+				Poll::Ready(output)
+			}
+			MemoizeState::Idle | MemoizeState::Done => {
+				panic!("`Memoize::poll_result` called without a projection in progress")
+			}
+		}
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		*self.project().state.get_mut() = MemoizeState::Idle;
+	}
+}
+
+impl<P, C, A, B> FusedPollProjectionMut<A, B> for Memoize<P, C, A, B>
+where
+	P: ProjectionMut<A, B>,
+	C: Cache<A, B>,
+	A: Clone,
+	B: Clone,
+{
+	fn is_terminated(&self) -> bool {
+		matches!(
+			unsafe { &*self.state.get() },
+			MemoizeState::Idle | MemoizeState::Done
+		)
+	}
+}
+// endregion
+// region: conversions
+/// [`IntoProjectionMut<A, B, X>`] and [`Cache<A, B>`] → [`FusedProjectionMut<A, B>`](`super::FusedProjectionMut`)
+#[must_use]
+pub fn memoize<P, X, C, A, B>(projection: P, cache: C) -> Memoize<P::IntoProjMut, C, A, B>
+where
+	P: IntoProjectionMut<A, B, X>,
+	C: Cache<A, B>,
+	A: Clone,
+	B: Clone,
+{
+	Memoize::new(projection.into_projection_mut(), cache)
+}
+// endregion
@@ -72,6 +72,7 @@
 //! Note that some adapters, like [`AsyncMut`], are dependently fused.
 //! If the underlying projection generates a [`FusedFuture`],
 //! then so do they *when called through their respective `Fused…` trait's `…fused(…)` method*.
+//! [`FuseAsyncMut`] and [`FuseRefAsyncMut`] instead track completion themselves, so they are fused for any [`Future`].
 //!
 //! > Practically speaking, it's the same underlying type, but **this is not guaranteed!**
 //!
@@ -134,18 +135,45 @@
 //! ```
 
 use crate::handles::PinHandleMut;
-use core::{future::Future, pin::Pin};
+use core::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
 use futures_core::FusedFuture;
 
 mod async_mut;
+mod branch;
+mod either;
+mod fan_out;
+mod fuse;
+mod fuse_async_mut;
+mod fuse_ref_async_mut;
 mod fused_blocking_mut;
 mod fused_mut_blocking_mut;
 mod fused_ref_blocking_mut;
+mod memoize;
+mod poll;
+mod project_static;
+mod retry;
+mod select;
+mod timeout;
 
 pub use async_mut::{from_async_mut, AsyncMut};
+pub use branch::{branch, Branch};
+pub use either::Either;
+pub use fan_out::{fan_out, try_fan_out, FanOut, Join, JoinSlot};
+pub use fuse::Fuse;
+pub use fuse_async_mut::{fuse_async_mut, FuseAsyncMut};
+pub use fuse_ref_async_mut::{fuse_ref_async_mut, FuseRefAsyncMut};
 pub use fused_blocking_mut::{from_blocking_mut, FusedBlockingMut};
 pub use fused_mut_blocking_mut::{from_mut_blocking_mut, FusedMutBlockingMut};
 pub use fused_ref_blocking_mut::{from_ref_blocking_mut, FusedRefBlockingMut};
+pub use memoize::{memoize, Cache, LruCache, Memoize};
+pub use project_static::ProjectStatic;
+pub use retry::{retry, ExponentialBackoff, FixedBackoff, Jitter, Retry, RetryPolicy};
+pub use select::{select, select_all, Select, SelectAll};
+pub use timeout::{timeout, Timeout};
 
 pub trait Projection<A, B>: ProjectionMut<A, B> {
 	fn project(self: Pin<&Self>, value: A) -> PinHandleMut<'_, dyn '_ + Future<Output = B>>;
@@ -169,6 +197,144 @@ pub trait FusedProjectionMut<A, B>: ProjectionMut<A, B> {
 	) -> PinHandleMut<'_, dyn '_ + FusedFuture<Output = B>>;
 }
 
+/// The lower-level, [`Context`]-driven form of [`ProjectionMut<A, B>`].
+///
+/// Instead of handing out a [`PinHandleMut`], the projection stores its progress in `self`:
+/// [`.start(…)`](`PollProjectionMut::start`) begins projecting a value,
+/// [`.poll_result(…)`](`PollProjectionMut::poll_result`) drives it to completion
+/// and [`.clear()`](`PollProjectionMut::clear`) drops any intermediate state.
+///
+/// This lets manual [`Stream`](`futures_core::Stream`) and [`Future`] implementations embed a projection without `dyn` calls.
+/// [`ProjectionMut<A, B>`] (and [`FusedProjectionMut<A, B>`] for [`FusedPollProjectionMut<A, B>`]) is blanket-implemented on top of this trait,
+/// so implement it instead of those where possible.
+///
+/// Implementors **must not** be [`Send`] or [`Sync`] unless that's also sound while a projection is in progress.
+/// Implementors whose projection in progress borrows from `self` (e.g. a future borrowing a structurally pinned inner projection)
+/// **must not** be [`Unpin`] either, since [`Pin`] doesn't keep [`Unpin`] values in place.
+///
+/// # Example
+///
+/// ```
+/// use core::{
+///     marker::PhantomData,
+///     pin::Pin,
+///     task::{Context, Poll},
+/// };
+/// use futures_core::Stream;
+/// use futures_util::{stream, StreamExt};
+/// use percolate::projection::{from_blocking_mut, PollProjectionMut};
+/// use pin_project::pin_project;
+/// use pollster::block_on;
+///
+/// #[pin_project]
+/// struct Then<S, P, B> {
+///     #[pin]
+///     input: S,
+///     #[pin]
+///     projection: P,
+///     busy: bool,
+///     _phantom: PhantomData<fn() -> B>,
+/// }
+///
+/// impl<S: Stream, P: PollProjectionMut<S::Item, B>, B> Stream for Then<S, P, B> {
+///     type Item = B;
+///
+///     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<B>> {
+///         let mut this = self.project();
+///         if !*this.busy {
+///             match this.input.poll_next(cx) {
+///                 Poll::Ready(Some(item)) => unsafe {
+///                     // Safety: `item` is owned, so it doesn't borrow anything.
+///                     this.projection.as_mut().start(item)
+///                 },
+///                 Poll::Ready(None) => return Poll::Ready(None),
+///                 Poll::Pending => return Poll::Pending,
+///             }
+///             *this.busy = true;
+///         }
+///         let output = this.projection.as_mut().poll_result(cx);
+///         if output.is_ready() {
+///             *this.busy = false;
+///             this.projection.clear();
+///         }
+///         output.map(Some)
+///     }
+/// }
+///
+/// let doubled = Then {
+///     input: stream::iter(1..=3),
+///     projection: from_blocking_mut(|x: i32| x * 2),
+///     busy: false,
+///     _phantom: PhantomData,
+/// };
+/// assert_eq!(block_on(doubled.collect::<Vec<_>>()), vec![2, 4, 6]);
+/// ```
+pub trait PollProjectionMut<A, B> {
+	/// Begins projecting `value`, replacing any projection still in progress.
+	///
+	/// # Safety
+	///
+	/// Anything borrowed by `value` **must** stay borrowed until `self` is [`.clear()`](`PollProjectionMut::clear`)ed,
+	/// which **must** happen before `self` is dropped if `value` borrows anything.
+	///
+	/// `self` stays in place while the projection is in progress only through its [`Pin`],
+	/// so implementations that borrow from it until then **must** be `!Unpin`.
+	unsafe fn start(self: Pin<&mut Self>, value: A);
+
+	/// Drives the projection begun by the last [`.start(…)`](`PollProjectionMut::start`) call.
+	///
+	/// # Panics
+	///
+	/// May panic if no projection is in progress, including after it completed (unless the projection is fused and reports [`.is_terminated()`](`FusedPollProjectionMut::is_terminated`)).
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B>;
+
+	/// Drops the projection in progress, if any.
+	fn clear(self: Pin<&mut Self>);
+}
+
+/// A [`PollProjectionMut<A, B>`] that keeps track of whether it may be [`.poll_result(…)`](`PollProjectionMut::poll_result`)ed.
+pub trait FusedPollProjectionMut<A, B>: PollProjectionMut<A, B> {
+	/// Whether no projection is in progress,
+	/// either because the last one completed or because none was started since the last [`.clear()`](`PollProjectionMut::clear`).
+	fn is_terminated(&self) -> bool;
+}
+
+/// The [`Future`] returned by [`StaticProjectionMut::project_static`] for a borrow of `'a`.
+///
+/// This stands in for a generic associated type, which isn't available on this crate's minimum supported Rust version.
+pub trait StaticProjectionMutFuture<'a, A, B> {
+	type Future: Future<Output = B>;
+}
+
+/// The statically dispatched form of [`ProjectionMut<A, B>`].
+///
+/// [`ProjectionMut::project`] returns a [`PinHandleMut`] to a `dyn` [`Future`], so each poll through it is an indirect call.
+/// This trait instead names the concrete future type, at the cost of object safety.
+///
+/// It's blanket-implemented (with [`ProjectStatic`]) over [`PollProjectionMut<A, B>`], so all projections in this crate implement it.
+///
+/// # Example
+///
+/// ```
+/// use core::pin::Pin;
+/// use percolate::projection::{from_blocking_mut, StaticProjectionMut};
+/// use pollster::block_on;
+///
+/// async fn project_twice<P: StaticProjectionMut<u8, u8>>(mut projection: Pin<&mut P>, value: u8) -> u8 {
+///     let once = projection.as_mut().project_static(value).await;
+///     projection.project_static(once).await
+/// }
+///
+/// let mut projection = Box::pin(from_blocking_mut(|x: u8| x + 1));
+/// assert_eq!(block_on(project_twice(projection.as_mut(), 1)), 3);
+/// ```
+pub trait StaticProjectionMut<A, B>: for<'a> StaticProjectionMutFuture<'a, A, B> {
+	fn project_static(
+		self: Pin<&mut Self>,
+		value: A,
+	) -> <Self as StaticProjectionMutFuture<'_, A, B>>::Future;
+}
+
 /// alias: [`for<'a> Projection<&'a A, B>`](`Projection`)
 pub trait RefProjection<A: ?Sized, B>:
 	for<'a> Projection<&'a A, B> + MutProjection<A, B> + RefProjectionMut<A, B>
@@ -349,7 +515,7 @@ pub trait IntoFusedProjection<A, B, X>:
 {
 	type IntoFusedProj: FusedProjection<A, B> + IntoFusedProjection<A, B, X>;
 	#[must_use]
-	fn into_fused_projection(self) -> Self::IntoProj;
+	fn into_fused_projection(self) -> Self::IntoFusedProj;
 }
 
 pub trait IntoProjectionMut<A, B, X>: Sized {
@@ -361,7 +527,7 @@ pub trait IntoProjectionMut<A, B, X>: Sized {
 pub trait IntoFusedProjectionMut<A, B, X>: Sized + IntoProjectionMut<A, B, X> {
 	type IntoFusedProjMut: FusedProjectionMut<A, B> + IntoFusedProjectionMut<A, B, X>;
 	#[must_use]
-	fn into_fused_projection_mut(self) -> Self::IntoProjMut;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut;
 }
 
 pub trait IntoRefProjection<A: ?Sized, B, X>:
@@ -0,0 +1,114 @@
+//! Blanket implementations of [`ProjectionMut<A, B>`] and [`FusedProjectionMut<A, B>`] over [`PollProjectionMut<A, B>`].
+
+use super::{FusedPollProjectionMut, FusedProjectionMut, PollProjectionMut, ProjectionMut};
+use crate::handles::{PinHandleMut, RunOnce, Runnable};
+use core::{
+	cell::UnsafeCell,
+	future::Future,
+	marker::PhantomData,
+	mem::transmute,
+	pin::Pin,
+	ptr::NonNull,
+	task::{Context, Poll},
+};
+use futures_core::FusedFuture;
+
+impl<P, A, B> ProjectionMut<A, B> for P
+where
+	P: PollProjectionMut<A, B>,
+{
+	fn project(self: Pin<&mut Self>, value: A) -> PinHandleMut<'_, dyn '_ + Future<Output = B>> {
+		unsafe {
+			// Safety: The handle clears `self` when dropped, before the mutable borrow is released.
+			let polling = Polling::start(self, value);
+			let future = transmute::<
+				NonNull<dyn '_ + Future<Output = B>>,
+				NonNull<dyn Future<Output = B>>,
+			>(polling);
+			let clear = transmute::<
+				NonNull<dyn '_ + Runnable<(), ()>>,
+				NonNull<dyn Runnable<(), ()>>,
+			>(polling);
+			PinHandleMut::new(
+				Pin::new_unchecked(&mut *future.as_ptr()),
+				Some(RunOnce::new(&*clear.as_ptr())),
+			)
+		}
+	}
+}
+
+impl<P, A, B> FusedProjectionMut<A, B> for P
+where
+	P: FusedPollProjectionMut<A, B>,
+{
+	fn project_fused(
+		self: Pin<&mut Self>,
+		value: A,
+	) -> PinHandleMut<'_, dyn '_ + FusedFuture<Output = B>> {
+		unsafe {
+			// Safety: The handle clears `self` when dropped, before the mutable borrow is released.
+			let polling = Polling::start(self, value);
+			let future = transmute::<
+				NonNull<dyn '_ + FusedFuture<Output = B>>,
+				NonNull<dyn FusedFuture<Output = B>>,
+			>(polling);
+			let clear = transmute::<
+				NonNull<dyn '_ + Runnable<(), ()>>,
+				NonNull<dyn Runnable<(), ()>>,
+			>(polling);
+			PinHandleMut::new(
+				Pin::new_unchecked(&mut *future.as_ptr()),
+				Some(RunOnce::new(&*clear.as_ptr())),
+			)
+		}
+	}
+}
+
+/// A [`PollProjectionMut<A, B>`] in progress, as [`Future`] and as [`Runnable`] that clears it.
+///
+/// `A` and `B` aren't known to outlive the handle, so the trait objects above have their lifetimes extended unsafely,
+/// as in the projection adapters that store references.
+#[repr(transparent)]
+struct Polling<P, A, B>(UnsafeCell<P>, PhantomData<fn(A) -> B>);
+
+impl<P, A, B> Polling<P, A, B>
+where
+	P: PollProjectionMut<A, B>,
+{
+	/// # Safety
+	///
+	/// See [`PollProjectionMut::start`]. The result aliases `projection`.
+	unsafe fn start(projection: Pin<&mut P>, value: A) -> NonNull<Self> {
+		let mut projection = NonNull::from(Pin::get_unchecked_mut(projection));
+		Pin::new_unchecked(projection.as_mut()).start(value);
+		projection.cast()
+	}
+}
+
+impl<P, A, B> Future for Polling<P, A, B>
+where
+	P: PollProjectionMut<A, B>,
+{
+	type Output = B;
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		unsafe { Pin::new_unchecked(&mut *self.0.get()) }.poll_result(cx)
+	}
+}
+
+impl<P, A, B> FusedFuture for Polling<P, A, B>
+where
+	P: FusedPollProjectionMut<A, B>,
+{
+	fn is_terminated(&self) -> bool {
+		unsafe { &*self.0.get() }.is_terminated()
+	}
+}
+
+impl<P, A, B> Runnable<(), ()> for Polling<P, A, B>
+where
+	P: PollProjectionMut<A, B>,
+{
+	fn run(&self, (): ()) {
+		unsafe { Pin::new_unchecked(&mut *self.0.get()) }.clear();
+	}
+}
@@ -0,0 +1,98 @@
+use super::{
+	FusedPollProjectionMut, PollProjectionMut, StaticProjectionMut, StaticProjectionMutFuture,
+};
+use core::{
+	future::Future,
+	marker::PhantomData,
+	pin::Pin,
+	ptr::NonNull,
+	task::{Context, Poll},
+};
+use futures_core::FusedFuture;
+
+/// The [`Future`] returned by [`StaticProjectionMut::project_static`] for any [`PollProjectionMut<A, B>`].
+///
+/// Dropping it [`.clear()`](`PollProjectionMut::clear`)s the projection.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ProjectStatic<'a, P, A, B>
+where
+	P: PollProjectionMut<A, B>,
+{
+	/// Not a [`Pin<&'a mut P>`] so that `P` isn't required to outlive `'a` (for [`StaticProjectionMutFuture`]).
+	projection: NonNull<P>,
+	_lifetime: PhantomData<&'a mut ()>,
+	_projection: PhantomData<fn(P) -> P>,
+	/// `A` is invariant so that borrows in it can't end while `self` exists.
+	_phantom: PhantomData<fn(A) -> (A, B)>,
+}
+
+// region: threading
+/// Behaves like [`&'a mut P`](`Pin`).
+unsafe impl<P, A, B> Send for ProjectStatic<'_, P, A, B> where P: Send + PollProjectionMut<A, B> {}
+/// Behaves like [`&'a mut P`](`Pin`).
+unsafe impl<P, A, B> Sync for ProjectStatic<'_, P, A, B> where P: Sync + PollProjectionMut<A, B> {}
+// endregion
+
+impl<'a, P, A, B> StaticProjectionMutFuture<'a, A, B> for P
+where
+	P: PollProjectionMut<A, B>,
+{
+	type Future = ProjectStatic<'a, P, A, B>;
+}
+
+impl<P, A, B> StaticProjectionMut<A, B> for P
+where
+	P: PollProjectionMut<A, B>,
+{
+	fn project_static(self: Pin<&mut Self>, value: A) -> ProjectStatic<'_, Self, A, B> {
+		unsafe {
+			// Safety: Borrows in `value` outlive the returned future, which clears `self` when dropped.
+			let mut projection = NonNull::from(Pin::get_unchecked_mut(self));
+			Pin::new_unchecked(projection.as_mut()).start(value);
+			ProjectStatic {
+				projection,
+				_lifetime: PhantomData,
+				_projection: PhantomData,
+				_phantom: PhantomData,
+			}
+		}
+	}
+}
+
+impl<P, A, B> ProjectStatic<'_, P, A, B>
+where
+	P: PollProjectionMut<A, B>,
+{
+	fn projection(&mut self) -> Pin<&mut P> {
+		unsafe { Pin::new_unchecked(self.projection.as_mut()) }
+	}
+}
+
+impl<P, A, B> Future for ProjectStatic<'_, P, A, B>
+where
+	P: PollProjectionMut<A, B>,
+{
+	type Output = B;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		self.get_mut().projection().poll_result(cx)
+	}
+}
+
+impl<P, A, B> FusedFuture for ProjectStatic<'_, P, A, B>
+where
+	P: FusedPollProjectionMut<A, B>,
+{
+	fn is_terminated(&self) -> bool {
+		unsafe { self.projection.as_ref() }.is_terminated()
+	}
+}
+
+impl<P, A, B> Drop for ProjectStatic<'_, P, A, B>
+where
+	P: PollProjectionMut<A, B>,
+{
+	fn drop(&mut self) {
+		self.projection().clear();
+	}
+}
@@ -0,0 +1,392 @@
+use super::{
+	FusedPollProjectionMut, IntoFusedProjectionMut, IntoProjectionMut, PollProjectionMut,
+	ProjectionMut,
+};
+use crate::{handles::ErasedFutureHandleMut, time::Timer};
+use core::{
+	cell::UnsafeCell,
+	convert::TryFrom,
+	mem,
+	pin::Pin,
+	task::{Context, Poll},
+	time::Duration,
+};
+use futures_core::Future;
+use pin_project::pin_project;
+
+/// Decides whether and when [`Retry`] makes another attempt.
+pub trait RetryPolicy<E, D> {
+	/// Called after the `attempt`th attempt (counting from 1) failed with `error`.
+	///
+	/// Returns how long to wait before the next attempt, or [`None`] to give up and return `error`.
+	fn retry(&mut self, attempt: usize, error: &E) -> Option<D>;
+}
+
+impl<R: ?Sized + RetryPolicy<E, D>, E, D> RetryPolicy<E, D> for &mut R {
+	fn retry(&mut self, attempt: usize, error: &E) -> Option<D> {
+		(**self).retry(attempt, error)
+	}
+}
+
+/// A [`RetryPolicy`] that waits the same `delay` between up to `max_attempts` attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedBackoff<D> {
+	pub delay: D,
+	pub max_attempts: usize,
+}
+
+impl<D> FixedBackoff<D> {
+	#[must_use]
+	pub fn new(delay: D, max_attempts: usize) -> Self {
+		Self {
+			delay,
+			max_attempts,
+		}
+	}
+}
+
+impl<E, D: Clone> RetryPolicy<E, D> for FixedBackoff<D> {
+	fn retry(&mut self, attempt: usize, _: &E) -> Option<D> {
+		(attempt < self.max_attempts).then(|| self.delay.clone())
+	}
+}
+
+/// A [`RetryPolicy`] that multiplies its delay by `factor` after each failed attempt,
+/// up to `max_delay`, and gives up after `max_attempts` attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExponentialBackoff {
+	pub initial_delay: Duration,
+	pub factor: u32,
+	pub max_delay: Duration,
+	pub max_attempts: usize,
+}
+
+impl ExponentialBackoff {
+	/// Creates a new [`ExponentialBackoff`] that doubles its delay each time, without limiting it.
+	#[must_use]
+	pub fn new(initial_delay: Duration, max_attempts: usize) -> Self {
+		Self {
+			initial_delay,
+			factor: 2,
+			max_delay: Duration::from_secs(u64::MAX),
+			max_attempts,
+		}
+	}
+}
+
+impl<E> RetryPolicy<E, Duration> for ExponentialBackoff {
+	fn retry(&mut self, attempt: usize, _: &E) -> Option<Duration> {
+		(attempt < self.max_attempts).then(|| {
+			u32::try_from(attempt - 1)
+				.ok()
+				.and_then(|exponent| self.factor.checked_pow(exponent))
+				.and_then(|factor| self.initial_delay.checked_mul(factor))
+				.map_or(self.max_delay, |delay| delay.min(self.max_delay))
+		})
+	}
+}
+
+/// A [`RetryPolicy`] that randomly shortens the delays of another one ("full jitter"),
+/// so that many clients retrying at once spread out.
+///
+/// The randomness is a seeded pseudo-random sequence, so this is deterministic for each `seed`.
+#[derive(Debug, Clone)]
+pub struct Jitter<R> {
+	policy: R,
+	state: u64,
+}
+
+impl<R> Jitter<R> {
+	#[must_use]
+	pub fn new(policy: R, seed: u64) -> Self {
+		Self {
+			policy,
+			// xorshift gets stuck on 0.
+			state: if seed == 0 {
+				0x9E37_79B9_7F4A_7C15
+			} else {
+				seed
+			},
+		}
+	}
+
+	/// xorshift64*
+	fn next_u32(&mut self) -> u32 {
+		self.state ^= self.state >> 12;
+		self.state ^= self.state << 25;
+		self.state ^= self.state >> 27;
+		u32::try_from(self.state.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 32).expect("unreachable")
+	}
+}
+
+impl<R: RetryPolicy<E, Duration>, E> RetryPolicy<E, Duration> for Jitter<R> {
+	fn retry(&mut self, attempt: usize, error: &E) -> Option<Duration> {
+		let delay = self.policy.retry(attempt, error)?;
+		// Can't overflow, since `Duration` holds less than 2⁹⁶ nanoseconds.
+		let nanos = (delay.as_nanos() * u128::from(self.next_u32())) >> 32;
+		Some(Duration::new(
+			u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX),
+			u32::try_from(nanos % 1_000_000_000).expect("unreachable"),
+		))
+	}
+}
+
+/// [`ProjectionMut<A, Result<B, E>>`](`ProjectionMut`), [`RetryPolicy<E, T::Duration>`](`RetryPolicy`) and [`Timer`]
+/// → [`FusedProjectionMut<A, Result<B, E>>`](`super::FusedProjectionMut`)
+///
+/// Failed attempts are retried with a clone of the original input for as long as `R` allows.
+/// The last error is returned once it gives up.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use ergo_pin::ergo_pin;
+/// use percolate::{
+///     projection::{retry, FixedBackoff, ProjectionMut},
+///     time::ManualClock,
+/// };
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn succeed_on_attempt(attempt: usize, max_attempts: usize) -> Result<usize, usize> {
+///     let clock = ManualClock::<1>::new();
+///     let mut attempts = 0;
+///     pin!(retry(
+///         |threshold: usize| {
+///             attempts += 1;
+///             if attempts >= threshold {
+///                 Ok(attempts)
+///             } else {
+///                 Err(attempts)
+///             }
+///         },
+///         FixedBackoff::new(Duration::from_secs(0), max_attempts),
+///         &clock,
+///     ))
+///     .project(attempt)
+///     .await
+/// }
+///
+/// assert_eq!(block_on(succeed_on_attempt(3, 3)), Ok(3));
+/// assert_eq!(block_on(succeed_on_attempt(4, 3)), Err(3));
+/// ```
+#[pin_project(!Unpin)]
+pub struct Retry<P, R, T, A, B, E>
+where
+	P: ProjectionMut<A, Result<B, E>>,
+	R: RetryPolicy<E, T::Duration>,
+	T: Timer,
+	A: Clone,
+{
+	// Declared before `projection` so that a leaked handle is dropped first.
+	state: UnsafeCell<RetryState<A, B, E>>,
+	policy: UnsafeCell<R>,
+	timer: UnsafeCell<T>,
+	/// Present while `state` is [`RetryState::Waiting`].
+	#[pin]
+	delay: UnsafeCell<Option<T::Delay>>,
+	#[pin]
+	projection: UnsafeCell<P>,
+}
+
+enum RetryState<A, B, E> {
+	Idle,
+	Attempting {
+		value: A,
+		attempt: usize,
+		future: ErasedFutureHandleMut<Result<B, E>>,
+	},
+	Waiting {
+		value: A,
+		attempt: usize,
+	},
+	Done,
+}
+
+impl<P, R, T, A, B, E> Retry<P, R, T, A, B, E>
+where
+	P: ProjectionMut<A, Result<B, E>>,
+	R: RetryPolicy<E, T::Duration>,
+	T: Timer,
+	A: Clone,
+{
+	#[must_use]
+	pub fn new(projection: P, policy: R, timer: T) -> Self {
+		Self {
+			state: RetryState::Idle.into(),
+			policy: policy.into(),
+			timer: timer.into(),
+			delay: None.into(),
+			projection: projection.into(),
+		}
+	}
+
+	/// # Safety
+	///
+	/// The previous attempt's handle, if any, **must** have been dropped already.
+	unsafe fn attempt(&self, value: A, attempt: usize) -> RetryState<A, B, E> {
+		let future = Pin::new_unchecked(&mut *self.projection.get()).project(value.clone());
+		RetryState::Attempting {
+			value,
+			attempt,
+			// Dropped by `.clear()` or when `self` is dropped.
+			future: ErasedFutureHandleMut::new(future),
+		}
+	}
+}
+
+// region: threading
+/// The erased handle in `state` only borrows `projection`.
+unsafe impl<P, R, T, A, B, E> Send for Retry<P, R, T, A, B, E>
+where
+	P: Send + ProjectionMut<A, Result<B, E>>,
+	R: Send + RetryPolicy<E, T::Duration>,
+	T: Send + Timer,
+	T::Delay: Send,
+	A: Send + Clone,
+	B: Send,
+	E: Send,
+{
+}
+/// The retried `A`, `policy`, `timer`, the delay and `projection` are only touched through [`Pin<&mut Retry>`](`Pin`). [`&Retry`](`Retry`) merely checks `state`'s variant.
+unsafe impl<P, R, T, A, B, E> Sync for Retry<P, R, T, A, B, E>
+where
+	P: ProjectionMut<A, Result<B, E>>,
+	R: RetryPolicy<E, T::Duration>,
+	T: Timer,
+	A: Clone,
+{
+}
+// endregion
+// region: projection impls
+impl<P, R, T, A, B, E> IntoProjectionMut<A, Result<B, E>, Self> for Retry<P, R, T, A, B, E>
+where
+	P: ProjectionMut<A, Result<B, E>>,
+	R: RetryPolicy<E, T::Duration>,
+	T: Timer,
+	A: Clone,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, R, T, A, B, E> IntoFusedProjectionMut<A, Result<B, E>, Self> for Retry<P, R, T, A, B, E>
+where
+	P: ProjectionMut<A, Result<B, E>>,
+	R: RetryPolicy<E, T::Duration>,
+	T: Timer,
+	A: Clone,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, R, T, A, B, E> PollProjectionMut<A, Result<B, E>> for Retry<P, R, T, A, B, E>
+where
+	P: ProjectionMut<A, Result<B, E>>,
+	R: RetryPolicy<E, T::Duration>,
+	T: Timer,
+	A: Clone,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: A) {
+		let this = self.into_ref();
+		*this.state.get() = RetryState::Idle;
+		*this.state.get() = this.attempt(value, 1);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<B, E>> {
+		let retry = self.into_ref().get_ref();
+		let state = unsafe { &mut *retry.state.get() };
+		loop {
+			match state {
+				RetryState::Attempting { future, .. } => {
+					let error = match future.poll(cx) {
+						Poll::Pending => return Poll::Pending,
+						Poll::Ready(Ok(output)) => {
+							*state = RetryState::Done;
+							return Poll::Ready(Ok(output));
+						}
+						Poll::Ready(Err(error)) => error,
+					};
+					let (value, attempt) = match mem::replace(state, RetryState::Done) {
+						RetryState::Attempting { value, attempt, .. } => (value, attempt),
+						_ => unreachable!(),
+					};
+					match unsafe { &mut *retry.policy.get() }.retry(attempt, &error) {
+						Some(duration) => {
+							// Assigned in place, so that a pinned delay is never moved.
+							*unsafe { &mut *retry.delay.get() } =
+								Some(unsafe { &mut *retry.timer.get() }.delay(duration));
+							*state = RetryState::Waiting { value, attempt };
+						}
+						None => return Poll::Ready(Err(error)),
+					}
+				}
+				RetryState::Waiting { .. } => {
+					let delay = unsafe { &mut *retry.delay.get() };
+					// Safety: `delay` is a pinned field that is only ever replaced in place.
+					match unsafe { Pin::new_unchecked(delay.as_mut().expect("unreachable")) }
+						.poll(cx)
+					{
+						Poll::Pending => return Poll::Pending,
+						Poll::Ready(()) => *delay = None,
+					}
+					match mem::replace(state, RetryState::Idle) {
+						RetryState::Waiting { value, attempt, .. } => {
+							*state = unsafe { retry.attempt(value, attempt + 1) }
+						}
+						_ => unreachable!(),
+					}
+				}
+				RetryState::Idle | RetryState::Done => {
+					panic!("`Retry::poll_result` called without a projection in progress")
+				}
+			}
+		}
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		let this = self.project();
+		*this.state.get_mut() = RetryState::Idle;
+		*unsafe { this.delay.get_unchecked_mut() }.get_mut() = None;
+	}
+}
+
+impl<P, R, T, A, B, E> FusedPollProjectionMut<A, Result<B, E>> for Retry<P, R, T, A, B, E>
+where
+	P: ProjectionMut<A, Result<B, E>>,
+	R: RetryPolicy<E, T::Duration>,
+	T: Timer,
+	A: Clone,
+{
+	fn is_terminated(&self) -> bool {
+		matches!(
+			unsafe { &*self.state.get() },
+			RetryState::Idle | RetryState::Done
+		)
+	}
+}
+// endregion
+// region: conversions
+/// [`IntoProjectionMut<A, Result<B, E>, X>`](`IntoProjectionMut`), [`RetryPolicy<E, T::Duration>`](`RetryPolicy`) and [`Timer`]
+/// → [`FusedProjectionMut<A, Result<B, E>>`](`super::FusedProjectionMut`)
+#[must_use]
+pub fn retry<P, X, R, T, A, B, E>(
+	projection: P,
+	policy: R,
+	timer: T,
+) -> Retry<P::IntoProjMut, R, T, A, B, E>
+where
+	P: IntoProjectionMut<A, Result<B, E>, X>,
+	R: RetryPolicy<E, T::Duration>,
+	T: Timer,
+	A: Clone,
+{
+	Retry::new(projection.into_projection_mut(), policy, timer)
+}
+// endregion
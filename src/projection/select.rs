@@ -0,0 +1,553 @@
+use super::{
+	Either, FusedPollProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
+	IntoFusedRefProjectionMut, IntoMutProjectionMut, IntoProjectionMut, IntoRefProjectionMut,
+	PollProjectionMut, RefProjectionMut,
+};
+use crate::{handles::ErasedFutureHandleMut, util::array_from_fn};
+use core::{
+	cell::UnsafeCell,
+	marker::PhantomData,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use pin_project::pin_project;
+
+/// Two [`RefProjectionMut<A, _>`](`RefProjectionMut`) → [`FusedRefProjectionMut<A, Either<B1, B2>>`](`super::FusedRefProjectionMut`)
+///
+/// Projects each value through both `left` and `right` concurrently and resolves with whichever output is available first.
+/// The other future is then dropped immediately.
+///
+/// If both are available at once, `left` wins.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::projection::{from_ref_blocking_mut, select, Either, RefProjectionMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn race(value: u8) -> Either<u8, bool> {
+///     pin!(select(
+///         from_ref_blocking_mut(|x: &u8| x + 1),
+///         from_ref_blocking_mut(|x: &u8| *x > 0),
+///     ))
+///     .project_ref(&value)
+///     .await
+/// }
+///
+/// assert_eq!(block_on(race(1)), Either::Left(2));
+/// ```
+#[pin_project(!Unpin)]
+pub struct Select<P1, P2, A: ?Sized, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	// Declared before the projections so that a leaked handle is dropped first.
+	state: UnsafeCell<SelectState<B1, B2>>,
+	#[pin]
+	left: P1,
+	#[pin]
+	right: P2,
+	_phantom: PhantomData<fn(&A)>,
+}
+
+enum SelectState<B1, B2> {
+	Idle,
+	Running(ErasedFutureHandleMut<B1>, ErasedFutureHandleMut<B2>),
+	Done,
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	#[must_use]
+	pub fn new(left: P1, right: P2) -> Self {
+		Self {
+			state: SelectState::Idle.into(),
+			left,
+			right,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+// region: threading
+/// The erased handles in `state` only borrow the projections and the borrowed input.
+unsafe impl<P1, P2, A: ?Sized, B1, B2> Send for Select<P1, P2, A, B1, B2>
+where
+	P1: Send + RefProjectionMut<A, B1>,
+	P2: Send + RefProjectionMut<A, B2>,
+	A: Sync,
+	B1: Send,
+	B2: Send,
+{
+}
+/// [`&Select`](`Select`) only reads whether `state` is running, never the handles it holds or either projection.
+unsafe impl<P1, P2, A: ?Sized, B1, B2> Sync for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+}
+// endregion
+// region: projection impls
+impl<P1, P2, A: ?Sized, B1, B2> IntoRefProjectionMut<A, Either<B1, B2>, Self>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	type IntoRefProjMut = Self;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		self
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> IntoMutProjectionMut<A, Either<B1, B2>, Self>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	type IntoMutProjMut = Self;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		self
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> IntoFusedRefProjectionMut<A, Either<B1, B2>, Self>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	type IntoFusedRefProjMut = Self;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		self
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> IntoFusedMutProjectionMut<A, Either<B1, B2>, Self>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	type IntoFusedMutProjMut = Self;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		self
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> IntoProjectionMut<&A, Either<B1, B2>, Self>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> IntoProjectionMut<&mut A, Either<B1, B2>, Self>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> IntoFusedProjectionMut<&A, Either<B1, B2>, Self>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> IntoFusedProjectionMut<&mut A, Either<B1, B2>, Self>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> PollProjectionMut<&A, Either<B1, B2>> for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &A) {
+		let this = self.project();
+		let state = this.state.get_mut();
+		*state = SelectState::Idle;
+		// Safety: Dropped by `.clear()` or when `self` is dropped.
+		*state = SelectState::Running(
+			ErasedFutureHandleMut::new(this.left.project_ref(value)),
+			ErasedFutureHandleMut::new(this.right.project_ref(value)),
+		);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Either<B1, B2>> {
+		let state = self.project().state.get_mut();
+		let output = match state {
+			SelectState::Running(left, right) => match left.poll(cx) {
+				Poll::Ready(output) => Either::Left(output),
+				Poll::Pending => match right.poll(cx) {
+					Poll::Ready(output) => Either::Right(output),
+					Poll::Pending => return Poll::Pending,
+				},
+			},
+			SelectState::Idle | SelectState::Done => {
+				panic!("`Select::poll_result` called without a projection in progress")
+			}
+		};
+		// Drops the losing future.
+		*state = SelectState::Done;
+		Poll::Ready(output)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		*self.project().state.get_mut() = SelectState::Idle;
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> PollProjectionMut<&mut A, Either<B1, B2>>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &mut A) {
+		PollProjectionMut::<&A, _>::start(self, value);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Either<B1, B2>> {
+		PollProjectionMut::<&A, _>::poll_result(self, cx)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		PollProjectionMut::<&A, Either<B1, B2>>::clear(self);
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> FusedPollProjectionMut<&A, Either<B1, B2>>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	fn is_terminated(&self) -> bool {
+		!matches!(unsafe { &*self.state.get() }, SelectState::Running(..))
+	}
+}
+
+impl<P1, P2, A: ?Sized, B1, B2> FusedPollProjectionMut<&mut A, Either<B1, B2>>
+	for Select<P1, P2, A, B1, B2>
+where
+	P1: RefProjectionMut<A, B1>,
+	P2: RefProjectionMut<A, B2>,
+{
+	fn is_terminated(&self) -> bool {
+		FusedPollProjectionMut::<&A, _>::is_terminated(self)
+	}
+}
+// endregion
+
+/// `N` [`RefProjectionMut<A, B>`] → [`FusedRefProjectionMut<A, (usize, B)>`](`super::FusedRefProjectionMut`)
+///
+/// Projects each value through all `projections` concurrently and resolves with the index and output of whichever completes first.
+/// The other futures are then dropped immediately.
+///
+/// If several outputs are available at once, the lowest index wins.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::projection::{from_ref_blocking_mut, select_all, RefProjectionMut};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn race(value: u8) -> (usize, u8) {
+///     let add = |n: u8| from_ref_blocking_mut(move |x: &u8| x + n);
+///     pin!(select_all([add(1), add(2)]))
+///         .project_ref(&value)
+///         .await
+/// }
+///
+/// assert_eq!(block_on(race(1)), (0, 2));
+/// ```
+#[pin_project(!Unpin)]
+pub struct SelectAll<P, A: ?Sized, B, const N: usize>
+where
+	P: RefProjectionMut<A, B>,
+{
+	// Declared before `projections` so that a leaked handle is dropped first.
+	state: UnsafeCell<SelectAllState<B, N>>,
+	#[pin]
+	projections: [P; N],
+	_phantom: PhantomData<fn(&A)>,
+}
+
+enum SelectAllState<B, const N: usize> {
+	Idle,
+	Running([ErasedFutureHandleMut<B>; N]),
+	Done,
+}
+
+impl<P, A: ?Sized, B, const N: usize> SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	/// # Panics
+	///
+	/// Iff `N` is 0.
+	#[must_use]
+	pub fn new(projections: [P; N]) -> Self {
+		assert!(N > 0, "Can't select from zero projections.");
+		Self {
+			state: SelectAllState::Idle.into(),
+			projections,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+// region: threading
+/// The erased handles in `state` only borrow `projections` and the borrowed input.
+unsafe impl<P, A: ?Sized, B, const N: usize> Send for SelectAll<P, A, B, N>
+where
+	P: Send + RefProjectionMut<A, B>,
+	A: Sync,
+	B: Send,
+{
+}
+/// Like [`&Select`](`Select`), [`&SelectAll`](`SelectAll`) only checks `state`'s variant, so none of the `N` projections or their handles are shared.
+unsafe impl<P, A: ?Sized, B, const N: usize> Sync for SelectAll<P, A, B, N> where
+	P: RefProjectionMut<A, B>
+{
+}
+// endregion
+// region: projection impls
+impl<P, A: ?Sized, B, const N: usize> IntoRefProjectionMut<A, (usize, B), Self>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoRefProjMut = Self;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> IntoMutProjectionMut<A, (usize, B), Self>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoMutProjMut = Self;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> IntoFusedRefProjectionMut<A, (usize, B), Self>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoFusedRefProjMut = Self;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> IntoFusedMutProjectionMut<A, (usize, B), Self>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoFusedMutProjMut = Self;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> IntoProjectionMut<&A, (usize, B), Self>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> IntoProjectionMut<&mut A, (usize, B), Self>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> IntoFusedProjectionMut<&A, (usize, B), Self>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> IntoFusedProjectionMut<&mut A, (usize, B), Self>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> PollProjectionMut<&A, (usize, B)> for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &A) {
+		let this = self.project();
+		let state = this.state.get_mut();
+		*state = SelectAllState::Idle;
+		let projections = this.projections.get_unchecked_mut();
+		*state = SelectAllState::Running(array_from_fn(|i| {
+			// Safety: Dropped by `.clear()` or when `self` is dropped.
+			ErasedFutureHandleMut::new(Pin::new_unchecked(&mut projections[i]).project_ref(value))
+		}));
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<(usize, B)> {
+		let state = self.project().state.get_mut();
+		let output = match state {
+			SelectAllState::Running(futures) => {
+				futures
+					.iter_mut()
+					.enumerate()
+					.find_map(|(i, future)| match future.poll(cx) {
+						Poll::Ready(output) => Some((i, output)),
+						Poll::Pending => None,
+					})
+			}
+			SelectAllState::Idle | SelectAllState::Done => {
+				panic!("`SelectAll::poll_result` called without a projection in progress")
+			}
+		};
+		match output {
+			Some(output) => {
+				// Drops the losing futures.
+				*state = SelectAllState::Done;
+				Poll::Ready(output)
+			}
+			None => Poll::Pending,
+		}
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		*self.project().state.get_mut() = SelectAllState::Idle;
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> PollProjectionMut<&mut A, (usize, B)>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &mut A) {
+		PollProjectionMut::<&A, _>::start(self, value);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<(usize, B)> {
+		PollProjectionMut::<&A, _>::poll_result(self, cx)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		PollProjectionMut::<&A, (usize, B)>::clear(self);
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> FusedPollProjectionMut<&A, (usize, B)>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	fn is_terminated(&self) -> bool {
+		!matches!(unsafe { &*self.state.get() }, SelectAllState::Running(_))
+	}
+}
+
+impl<P, A: ?Sized, B, const N: usize> FusedPollProjectionMut<&mut A, (usize, B)>
+	for SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	fn is_terminated(&self) -> bool {
+		FusedPollProjectionMut::<&A, _>::is_terminated(self)
+	}
+}
+// endregion
+// region: conversions
+/// Two [`IntoRefProjectionMut<A, _, X>`](`IntoRefProjectionMut`) → [`FusedRefProjectionMut<A, Either<B1, B2>>`](`super::FusedRefProjectionMut`)
+#[must_use]
+pub fn select<P1, P2, X1, X2, A: ?Sized, B1, B2>(
+	left: P1,
+	right: P2,
+) -> Select<P1::IntoRefProjMut, P2::IntoRefProjMut, A, B1, B2>
+where
+	P1: IntoRefProjectionMut<A, B1, X1>,
+	P2: IntoRefProjectionMut<A, B2, X2>,
+{
+	Select::new(
+		left.into_ref_projection_mut(),
+		right.into_ref_projection_mut(),
+	)
+}
+
+/// `N` [`RefProjectionMut<A, B>`] → [`FusedRefProjectionMut<A, (usize, B)>`](`super::FusedRefProjectionMut`)
+///
+/// # Panics
+///
+/// Iff `N` is 0.
+#[must_use]
+pub fn select_all<P, A: ?Sized, B, const N: usize>(projections: [P; N]) -> SelectAll<P, A, B, N>
+where
+	P: RefProjectionMut<A, B>,
+{
+	SelectAll::new(projections)
+}
+// endregion
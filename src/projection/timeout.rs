@@ -0,0 +1,204 @@
+use super::{
+	FusedPollProjectionMut, IntoFusedProjectionMut, IntoProjectionMut, PollProjectionMut,
+	ProjectionMut,
+};
+use crate::{
+	handles::ErasedFutureHandleMut,
+	time::{Elapsed, Timer},
+};
+use core::{
+	cell::UnsafeCell,
+	marker::PhantomData,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use futures_core::Future;
+use pin_project::pin_project;
+
+/// [`ProjectionMut<A, B>`] and [`Timer`] → [`FusedProjectionMut<A, Result<B, Elapsed>>`](`super::FusedProjectionMut`)
+///
+/// Each projection is given `duration` to complete, after which it is dropped and [`Elapsed`] is returned instead.
+///
+/// # Example
+///
+/// ```
+/// use core::{task::{Context, Poll}, time::Duration};
+/// use futures_util::{future::pending, task::noop_waker};
+/// use percolate::{
+///     projection::{from_async_mut, timeout, ProjectionMut},
+///     time::{Elapsed, ManualClock},
+/// };
+///
+/// let clock = ManualClock::<1>::new();
+/// let mut projection = Box::pin(timeout(
+///     from_async_mut(|()| pending::<()>()),
+///     &clock,
+///     Duration::from_secs(1),
+/// ));
+/// let waker = noop_waker();
+/// let mut cx = Context::from_waker(&waker);
+///
+/// let mut future = projection.as_mut().project(());
+/// assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+/// clock.advance(Duration::from_secs(1));
+/// assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(Err(Elapsed)));
+/// ```
+#[pin_project(!Unpin)]
+pub struct Timeout<P, T, A, B>
+where
+	P: ProjectionMut<A, B>,
+	T: Timer,
+{
+	// Declared before `projection` so that a leaked handle is dropped first.
+	state: UnsafeCell<TimeoutState<B>>,
+	/// Present while `state` is [`TimeoutState::Running`].
+	#[pin]
+	delay: Option<T::Delay>,
+	timer: T,
+	duration: T::Duration,
+	#[pin]
+	projection: P,
+	_phantom: PhantomData<A>,
+}
+
+enum TimeoutState<B> {
+	Idle,
+	Running(ErasedFutureHandleMut<B>),
+	Done,
+}
+
+impl<P, T, A, B> Timeout<P, T, A, B>
+where
+	P: ProjectionMut<A, B>,
+	T: Timer,
+{
+	#[must_use]
+	pub fn new(projection: P, timer: T, duration: T::Duration) -> Self {
+		Self {
+			state: TimeoutState::Idle.into(),
+			delay: None,
+			timer,
+			duration,
+			projection,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+// region: threading
+/// The erased handle in `state` only borrows `projection`.
+unsafe impl<P, T, A, B> Send for Timeout<P, T, A, B>
+where
+	P: Send + ProjectionMut<A, B>,
+	T: Send + Timer,
+	T::Duration: Send,
+	T::Delay: Send,
+	B: Send,
+{
+}
+/// `.is_terminated()` is [`&Timeout`](`Timeout`)'s only method and just matches on `state`, so the timer, the delay and `projection` aren't shared.
+unsafe impl<P, T, A, B> Sync for Timeout<P, T, A, B>
+where
+	P: ProjectionMut<A, B>,
+	T: Timer,
+{
+}
+// endregion
+// region: projection impls
+impl<P, T, A, B> IntoProjectionMut<A, Result<B, Elapsed>, Self> for Timeout<P, T, A, B>
+where
+	P: ProjectionMut<A, B>,
+	T: Timer,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, T, A, B> IntoFusedProjectionMut<A, Result<B, Elapsed>, Self> for Timeout<P, T, A, B>
+where
+	P: ProjectionMut<A, B>,
+	T: Timer,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, T, A, B> PollProjectionMut<A, Result<B, Elapsed>> for Timeout<P, T, A, B>
+where
+	P: ProjectionMut<A, B>,
+	T: Timer,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: A) {
+		let mut this = self.project();
+		let state = this.state.get_mut();
+		*state = TimeoutState::Idle;
+		this.delay
+			.set(Some(this.timer.delay(this.duration.clone())));
+		*state = TimeoutState::Running(
+			// Safety: Dropped by `.clear()` or when `self` is dropped.
+			ErasedFutureHandleMut::new(this.projection.project(value)),
+		);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<B, Elapsed>> {
+		let mut this = self.project();
+		let state = this.state.get_mut();
+		let output = match state {
+			TimeoutState::Running(future) => match future.poll(cx) {
+				Poll::Ready(output) => Ok(output),
+				Poll::Pending => match this
+					.delay
+					.as_mut()
+					.as_pin_mut()
+					.expect("unreachable")
+					.poll(cx)
+				{
+					Poll::Ready(()) => Err(Elapsed),
+					Poll::Pending => return Poll::Pending,
+				},
+			},
+			TimeoutState::Idle | TimeoutState::Done => {
+				panic!("`Timeout::poll_result` called without a projection in progress")
+			}
+		};
+		*state = TimeoutState::Done;
+		this.delay.set(None);
+		Poll::Ready(output)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		let mut this = self.project();
+		*this.state.get_mut() = TimeoutState::Idle;
+		this.delay.set(None);
+	}
+}
+
+impl<P, T, A, B> FusedPollProjectionMut<A, Result<B, Elapsed>> for Timeout<P, T, A, B>
+where
+	P: ProjectionMut<A, B>,
+	T: Timer,
+{
+	fn is_terminated(&self) -> bool {
+		!matches!(unsafe { &*self.state.get() }, TimeoutState::Running(..))
+	}
+}
+// endregion
+// region: conversions
+/// [`IntoProjectionMut<A, B, X>`] and [`Timer`] → [`FusedProjectionMut<A, Result<B, Elapsed>>`](`super::FusedProjectionMut`)
+#[must_use]
+pub fn timeout<P, X, T, A, B>(
+	projection: P,
+	timer: T,
+	duration: T::Duration,
+) -> Timeout<P::IntoProjMut, T, A, B>
+where
+	P: IntoProjectionMut<A, B, X>,
+	T: Timer,
+{
+	Timeout::new(projection.into_projection_mut(), timer, duration)
+}
+// endregion
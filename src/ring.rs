@@ -0,0 +1,552 @@
+//! Fixed-capacity ring buffer building blocks.
+//!
+//! [`RingIndex`] does the wrapping index arithmetic, and [`ArrayRing`] is an inline double-ended queue built on it.
+//! Both are `no_std` and don't allocate.
+
+use crate::util::array_from_fn;
+use core::{
+	convert::TryFrom,
+	fmt::{self, Debug, Display, Formatter},
+	iter::FusedIterator,
+	mem::MaybeUninit,
+	ops::{Add, AddAssign, Sub, SubAssign},
+	ptr, slice,
+};
+
+/// An index into a ring of `N` slots, i.e. an integer modulo `N`.
+///
+/// Adding or subtracting a [`usize`] wraps around,
+/// and subtracting another [`RingIndex`] yields the forward distance from it.
+///
+/// There are no valid indices for `N == 0`.
+///
+/// # Example
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use percolate::ring::RingIndex;
+///
+/// let index = RingIndex::<4>::try_from(3).unwrap();
+/// assert_eq!((index + 2).get(), 1);
+/// assert_eq!((index - 5).get(), 2);
+/// assert_eq!((index + 2) - index, 2);
+/// assert_eq!(index - (index + 2), 2);
+/// assert!(RingIndex::<4>::try_from(4).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RingIndex<const N: usize>(usize);
+
+impl<const N: usize> RingIndex<N> {
+	/// Creates a new [`RingIndex`] iff `index` is less than `N`.
+	#[must_use]
+	pub fn new(index: usize) -> Option<Self> {
+		(index < N).then(|| Self(index))
+	}
+
+	/// The index as plain [`usize`] in `0..N`.
+	#[must_use]
+	pub const fn get(self) -> usize {
+		self.0
+	}
+}
+
+impl<const N: usize> From<RingIndex<N>> for usize {
+	fn from(index: RingIndex<N>) -> Self {
+		index.0
+	}
+}
+
+impl<const N: usize> TryFrom<usize> for RingIndex<N> {
+	type Error = OutOfRange;
+
+	fn try_from(index: usize) -> Result<Self, OutOfRange> {
+		Self::new(index).ok_or(OutOfRange)
+	}
+}
+
+/// The error returned when converting a [`usize`] that isn't less than `N` into a [`RingIndex<N>`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfRange;
+
+impl Display for OutOfRange {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("ring index out of range")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
+impl<const N: usize> Add<usize> for RingIndex<N> {
+	type Output = Self;
+
+	fn add(self, rhs: usize) -> Self::Output {
+		let rhs = rhs % N;
+		Self(if rhs < N - self.0 {
+			self.0 + rhs
+		} else {
+			self.0 - (N - rhs)
+		})
+	}
+}
+impl<const N: usize> Add<usize> for &RingIndex<N> {
+	type Output = RingIndex<N>;
+
+	fn add(self, rhs: usize) -> Self::Output {
+		*self + rhs
+	}
+}
+impl<const N: usize> AddAssign<usize> for RingIndex<N> {
+	fn add_assign(&mut self, rhs: usize) {
+		*self = *self + rhs;
+	}
+}
+
+impl<const N: usize> Sub<usize> for RingIndex<N> {
+	type Output = Self;
+
+	fn sub(self, rhs: usize) -> Self::Output {
+		let rhs = rhs % N;
+		Self(if rhs <= self.0 {
+			self.0 - rhs
+		} else {
+			self.0 + (N - rhs)
+		})
+	}
+}
+impl<const N: usize> SubAssign<usize> for RingIndex<N> {
+	fn sub_assign(&mut self, rhs: usize) {
+		*self = *self - rhs;
+	}
+}
+
+/// The forward distance from `rhs` to `self`.
+impl<const N: usize> Sub for RingIndex<N> {
+	type Output = usize;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		if self.0 >= rhs.0 {
+			self.0 - rhs.0
+		} else {
+			N - rhs.0 + self.0
+		}
+	}
+}
+impl<Rhs, const N: usize> Sub<&Rhs> for RingIndex<N>
+where
+	Self: Sub<Rhs>,
+	Rhs: Copy,
+{
+	type Output = <Self as Sub<Rhs>>::Output;
+
+	fn sub(self, rhs: &Rhs) -> Self::Output {
+		self - *rhs
+	}
+}
+impl<Rhs, const N: usize> Sub<Rhs> for &RingIndex<N>
+where
+	RingIndex<N>: Sub<Rhs>,
+{
+	type Output = <RingIndex<N> as Sub<Rhs>>::Output;
+
+	fn sub(self, rhs: Rhs) -> Self::Output {
+		*self - rhs
+	}
+}
+
+/// An inline double-ended queue of up to `N` items.
+///
+/// Items are dropped along with the ring.
+///
+/// # Example
+///
+/// ```
+/// use percolate::ring::ArrayRing;
+///
+/// let mut ring = ArrayRing::<_, 3>::new();
+/// assert_eq!(ring.push_back(2), Ok(()));
+/// assert_eq!(ring.push_back(3), Ok(()));
+/// assert_eq!(ring.push_front(1), Ok(()));
+/// assert_eq!(ring.push_back(4), Err(4));
+///
+/// assert_eq!(ring.pop_front(), Some(1));
+/// assert_eq!(ring.push_back(4), Ok(()));
+/// assert_eq!(ring.get(2), Some(&4));
+/// assert!(ring.iter().copied().eq([2, 3, 4].iter().copied()));
+///
+/// assert_eq!(ring.pop_front(), Some(2));
+/// assert_eq!(ring.push_back(5), Ok(()));
+/// assert_eq!(ring.as_slices(), (&[3, 4][..], &[5][..]));
+/// ```
+pub struct ArrayRing<T, const N: usize> {
+	buffer: [MaybeUninit<T>; N],
+	/// Only meaningful while `len > 0`, as there's no valid [`RingIndex<0>`].
+	start: RingIndex<N>,
+	len: usize,
+}
+
+impl<T, const N: usize> ArrayRing<T, N> {
+	/// Creates a new empty [`ArrayRing`].
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			buffer: array_from_fn(|_| MaybeUninit::uninit()),
+			start: RingIndex(0),
+			len: 0,
+		}
+	}
+
+	/// The maximum number of items, `N`.
+	#[must_use]
+	pub const fn capacity(&self) -> usize {
+		N
+	}
+
+	/// The current number of items.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	#[must_use]
+	pub const fn is_full(&self) -> bool {
+		self.len == N
+	}
+
+	/// Appends `item` at the back, or returns it if `self` is already full.
+	///
+	/// # Errors
+	///
+	/// Iff `self` already contains `N` items.
+	pub fn push_back(&mut self, item: T) -> Result<(), T> {
+		if self.is_full() {
+			return Err(item);
+		}
+		self.buffer[(self.start + self.len).get()] = MaybeUninit::new(item);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Prepends `item` at the front, or returns it if `self` is already full.
+	///
+	/// # Errors
+	///
+	/// Iff `self` already contains `N` items.
+	pub fn push_front(&mut self, item: T) -> Result<(), T> {
+		if self.is_full() {
+			return Err(item);
+		}
+		self.start -= 1;
+		self.buffer[self.start.get()] = MaybeUninit::new(item);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Removes and returns the front item, if any.
+	pub fn pop_front(&mut self) -> Option<T> {
+		if self.is_empty() {
+			return None;
+		}
+		let i = self.start.get();
+		self.start += 1;
+		self.len -= 1;
+		Some(unsafe {
+			// Safety: Initialised, and marked as uninitialised directly above.
+			ptr::read(self.buffer[i].as_ptr())
+		})
+	}
+
+	/// Removes and returns the back item, if any.
+	pub fn pop_back(&mut self) -> Option<T> {
+		if self.is_empty() {
+			return None;
+		}
+		self.len -= 1;
+		Some(unsafe {
+			// Safety: Initialised, and marked as uninitialised directly above.
+			ptr::read(self.buffer[(self.start + self.len).get()].as_ptr())
+		})
+	}
+
+	/// Removes and drops all items.
+	pub fn clear(&mut self) {
+		let (front, back) = self.as_mut_slices();
+		let (front, back): (*mut [T], *mut [T]) = (front, back);
+		self.len = 0;
+		unsafe {
+			// Safety: These were initialised and are now marked as uninitialised.
+			// If dropping `front` panics, `back` is leaked, which is sound.
+			ptr::drop_in_place(front);
+			ptr::drop_in_place(back);
+		}
+	}
+
+	/// The item `index` places from the front, if any.
+	#[must_use]
+	pub fn get(&self, index: usize) -> Option<&T> {
+		(index < self.len).then(|| unsafe {
+			// Safety: The `len` slots from `start` (wrapping) are initialised.
+			&*self.buffer[(self.start + index).get()].as_ptr()
+		})
+	}
+
+	/// The item `index` places from the front, if any, mutably.
+	#[must_use]
+	pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		if index < self.len {
+			Some(unsafe {
+				// Safety: The `len` slots from `start` (wrapping) are initialised.
+				&mut *self.buffer[(self.start + index).get()].as_mut_ptr()
+			})
+		} else {
+			None
+		}
+	}
+
+	#[must_use]
+	pub fn front(&self) -> Option<&T> {
+		self.get(0)
+	}
+
+	#[must_use]
+	pub fn front_mut(&mut self) -> Option<&mut T> {
+		self.get_mut(0)
+	}
+
+	#[must_use]
+	pub fn back(&self) -> Option<&T> {
+		self.len.checked_sub(1).and_then(|index| self.get(index))
+	}
+
+	#[must_use]
+	pub fn back_mut(&mut self) -> Option<&mut T> {
+		match self.len.checked_sub(1) {
+			Some(index) => self.get_mut(index),
+			None => None,
+		}
+	}
+
+	/// The items in order, as up to two contiguous slices.
+	///
+	/// The second slice is empty unless the items wrap around the end of the buffer.
+	#[must_use]
+	pub fn as_slices(&self) -> (&[T], &[T]) {
+		let (start, front, back) = self.split();
+		let buffer = self.buffer.as_ptr().cast::<T>();
+		unsafe {
+			// Safety: These are exactly the initialised slots.
+			(
+				slice::from_raw_parts(buffer.add(start), front),
+				slice::from_raw_parts(buffer, back),
+			)
+		}
+	}
+
+	/// The items in order, as up to two contiguous mutable slices.
+	///
+	/// The second slice is empty unless the items wrap around the end of the buffer.
+	#[must_use]
+	pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+		let (start, front, back) = self.split();
+		let buffer = self.buffer.as_mut_ptr().cast::<T>();
+		unsafe {
+			// Safety: These are exactly the initialised slots, and `back` ends before `start`.
+			(
+				slice::from_raw_parts_mut(buffer.add(start), front),
+				slice::from_raw_parts_mut(buffer, back),
+			)
+		}
+	}
+
+	pub fn iter(&self) -> Iter<'_, T> {
+		let (front, back) = self.as_slices();
+		Iter {
+			front: front.iter(),
+			back: back.iter(),
+		}
+	}
+
+	pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+		let (front, back) = self.as_mut_slices();
+		IterMut {
+			front: front.iter_mut(),
+			back: back.iter_mut(),
+		}
+	}
+
+	/// The physical start and the lengths of the unwrapped and wrapped parts.
+	fn split(&self) -> (usize, usize, usize) {
+		if self.is_empty() {
+			return (0, 0, 0);
+		}
+		let start = self.start.get();
+		let front = self.len.min(N - start);
+		(start, front, self.len - front)
+	}
+}
+
+impl<T, const N: usize> Default for ArrayRing<T, N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, const N: usize> Drop for ArrayRing<T, N> {
+	fn drop(&mut self) {
+		self.clear();
+	}
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayRing<T, N> {
+	fn clone(&self) -> Self {
+		let mut clone = Self::new();
+		for item in self {
+			clone
+				.push_back(item.clone())
+				.unwrap_or_else(|_| unreachable!("`clone` has the same capacity"));
+		}
+		clone
+	}
+}
+
+impl<T: Debug, const N: usize> Debug for ArrayRing<T, N> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_list().entries(self).finish()
+	}
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayRing<T, N> {
+	fn eq(&self, other: &Self) -> bool {
+		self.iter().eq(other)
+	}
+}
+impl<T: Eq, const N: usize> Eq for ArrayRing<T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayRing<T, N> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayRing<T, N> {
+	type Item = &'a mut T;
+	type IntoIter = IterMut<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+
+impl<T, const N: usize> IntoIterator for ArrayRing<T, N> {
+	type Item = T;
+	type IntoIter = IntoIter<T, N>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIter(self)
+	}
+}
+
+/// The borrowing [`Iterator`] of an [`ArrayRing`], front to back.
+pub struct Iter<'a, T> {
+	front: slice::Iter<'a, T>,
+	back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.front.next().or_else(|| self.back.next())
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.front.len() + self.back.len();
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.back.next_back().or_else(|| self.front.next_back())
+	}
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+	fn clone(&self) -> Self {
+		Self {
+			front: self.front.clone(),
+			back: self.back.clone(),
+		}
+	}
+}
+
+/// The mutably borrowing [`Iterator`] of an [`ArrayRing`], front to back.
+pub struct IterMut<'a, T> {
+	front: slice::IterMut<'a, T>,
+	back: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+	type Item = &'a mut T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.front.next() {
+			Some(item) => Some(item),
+			None => self.back.next(),
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.front.len() + self.back.len();
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match self.back.next_back() {
+			Some(item) => Some(item),
+			None => self.front.next_back(),
+		}
+	}
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// The owning [`Iterator`] of an [`ArrayRing`], front to back.
+///
+/// Remaining items are dropped along with it.
+pub struct IntoIter<T, const N: usize>(ArrayRing<T, N>);
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.pop_front()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.0.len(), Some(self.0.len()))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.pop_back()
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
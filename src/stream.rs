@@ -1,5 +1,22 @@
 //! [`Stream`](`futures_core::Stream`) utilities.
 
+mod broadcast;
+mod chunks;
+mod peek1_stream;
 mod peek_stream;
+mod positioned;
+mod project_buffered;
+mod windows;
 
+#[cfg(feature = "alloc")]
+pub use broadcast::VecBroadcast;
+pub use broadcast::{Broadcast, BroadcastCursor};
+pub use chunks::{chunks, Chunk, Chunks, PartialChunk, PartialChunkIntoIter};
+pub use peek1_stream::Peek1Stream;
 pub use peek_stream::PeekStream;
+pub use positioned::{ByteOffset, ItemIndex, LineColumn, Position, Positioned};
+pub use project_buffered::{
+	project_all, project_buffer_unordered, project_buffered, ProjectBufferUnordered,
+	ProjectBuffered,
+};
+pub use windows::{windows, NextWindow, Windows};
@@ -0,0 +1,585 @@
+use crate::{ring::ArrayRing, util::array_from_fn};
+use core::{
+	cell::RefCell,
+	num::NonZeroUsize,
+	pin::Pin,
+	task::{Context, Poll, Waker},
+};
+use futures_core::{ready, FusedStream, Stream};
+use futures_util::future::poll_fn;
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+
+/// A [`Stream`] adapter that replays each item to multiple [`BroadcastCursor`]s, buffering up to `CAPACITY` items.
+///
+/// Up to `CURSORS` cursors can exist at the same time.
+/// Once the slowest cursor falls `CAPACITY` items behind the fastest, the latter waits for it to catch up.
+/// (This includes finding out whether `input` has ended.)
+///
+/// Items are cloned for each cursor except the last to retrieve them, which receives the buffered original.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use futures_util::{future::FutureExt as _, stream::{self, StreamExt as _}};
+/// use percolate::stream::Broadcast;
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn demo() {
+///     let broadcast = pin!(Broadcast::<_, 2, 2>::new(stream::iter(1..=3).fuse()));
+///     let mut fast = broadcast.as_ref().cursor().unwrap();
+///     let mut slow = broadcast.as_ref().cursor().unwrap();
+///
+///     assert_eq!(fast.next().await, Some(1));
+///     assert_eq!(fast.next().await, Some(2));
+///     assert_eq!(fast.next().now_or_never(), None); // Backpressure: `slow` is 2 items behind.
+///
+///     assert_eq!(slow.next().await, Some(1));
+///     assert_eq!(fast.next().await, Some(3));
+///
+///     assert_eq!(slow.collect::<Vec<_>>().await, [2, 3]);
+///     assert_eq!(fast.next().await, None);
+/// }
+///
+/// block_on(demo());
+/// ```
+pub struct Broadcast<Input: FusedStream, const CAPACITY: usize, const CURSORS: usize> {
+	shared: ArrayShared<Input, CAPACITY, CURSORS>,
+}
+
+impl<Input: FusedStream, const CAPACITY: usize, const CURSORS: usize>
+	Broadcast<Input, CAPACITY, CURSORS>
+{
+	/// Creates a new [`Broadcast`] without any cursors.
+	///
+	/// # Panics
+	///
+	/// Iff `CAPACITY` is 0.
+	#[must_use]
+	pub fn new(input: Input) -> Self {
+		assert!(CAPACITY > 0, "`CAPACITY` must be greater than 0");
+		Self {
+			shared: Shared::new(input, ArrayRing::new(), array_from_fn(|_| None)),
+		}
+	}
+
+	/// Creates a new cursor at the oldest item still buffered, or [`None`] if there already are `CURSORS` cursors.
+	#[must_use]
+	pub fn cursor(self: Pin<&Self>) -> Option<BroadcastCursor<'_, Input::Item>>
+	where
+		Input::Item: Clone,
+	{
+		let shared = unsafe {
+			// Safety: Structural pinning. `shared` is never moved out of.
+			self.map_unchecked(|this| &this.shared)
+		};
+		BroadcastCursor::new(shared)
+	}
+}
+
+/// A [`Broadcast`] that allocates its buffer and cursor list as needed.
+///
+/// Up to `capacity` items are buffered, and there's no limit on the number of cursors.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use futures_util::stream::{self, StreamExt as _};
+/// use percolate::stream::VecBroadcast;
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn demo() {
+///     let broadcast = pin!(VecBroadcast::new(stream::iter(1..=3).fuse(), 16));
+///     let cursors = [broadcast.as_ref().cursor(), broadcast.as_ref().cursor(), broadcast.as_ref().cursor()];
+///     for cursor in cursors.iter() {
+///         assert_eq!(cursor.fork().unwrap().collect::<Vec<_>>().await, [1, 2, 3]);
+///     }
+/// }
+///
+/// block_on(demo());
+/// ```
+#[cfg(feature = "alloc")]
+pub struct VecBroadcast<Input: FusedStream> {
+	shared: VecShared<Input>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Input: FusedStream> VecBroadcast<Input> {
+	/// Creates a new [`VecBroadcast`] without any cursors.
+	///
+	/// # Panics
+	///
+	/// Iff `capacity` is 0.
+	#[must_use]
+	pub fn new(input: Input, capacity: usize) -> Self {
+		assert!(capacity > 0, "`capacity` must be greater than 0");
+		Self {
+			shared: Shared::new(
+				input,
+				Growable {
+					items: VecDeque::new(),
+					capacity,
+				},
+				Vec::new(),
+			),
+		}
+	}
+
+	/// Creates a new cursor at the oldest item still buffered.
+	#[must_use]
+	pub fn cursor(self: Pin<&Self>) -> BroadcastCursor<'_, Input::Item>
+	where
+		Input::Item: Clone,
+	{
+		let shared = unsafe {
+			// Safety: Structural pinning. `shared` is never moved out of.
+			self.map_unchecked(|this| &this.shared)
+		};
+		match BroadcastCursor::new(shared) {
+			Some(cursor) => cursor,
+			None => unreachable!("`Vec` slots never run out"),
+		}
+	}
+}
+
+/// An independent read position in a [`Broadcast`] or [`VecBroadcast`].
+///
+/// Dropping a cursor releases the items only it was still waiting for.
+pub struct BroadcastCursor<'a, Item> {
+	hub: Pin<&'a dyn Hub<Item>>,
+	id: usize,
+}
+
+impl<'a, Item> BroadcastCursor<'a, Item> {
+	fn new(hub: Pin<&'a dyn Hub<Item>>) -> Option<Self> {
+		let id = hub.insert(None)?;
+		Some(Self { hub, id })
+	}
+
+	/// Creates a new cursor at the same position as `self`.
+	///
+	/// Returns [`None`] iff the respective [`Broadcast`] is already at its cursor limit.
+	#[must_use]
+	pub fn fork(&self) -> Option<Self> {
+		let id = self.hub.insert(Some(self.id))?;
+		Some(Self { hub: self.hub, id })
+	}
+
+	/// Attempts to peek `depth` items ahead of this cursor.
+	///
+	/// # Panics
+	///
+	/// Iff `depth` exceeds the buffer capacity.
+	pub fn poll_peek_n(&mut self, depth: NonZeroUsize, cx: &mut Context<'_>) -> Poll<Option<Item>> {
+		self.hub.poll_peek_n(self.id, depth.get(), cx)
+	}
+
+	/// Peeks `depth` items ahead of this cursor.
+	///
+	/// # Panics
+	///
+	/// Iff `depth` exceeds the buffer capacity.
+	pub async fn peek_n(&mut self, depth: NonZeroUsize) -> Option<Item> {
+		poll_fn(|cx| self.poll_peek_n(depth, cx)).await
+	}
+
+	/// Peeks the next item for this cursor.
+	pub async fn peek_1(&mut self) -> Option<Item> {
+		poll_fn(|cx| self.hub.poll_peek_n(self.id, 1, cx)).await
+	}
+}
+
+impl<Item> Stream for BroadcastCursor<'_, Item> {
+	type Item = Item;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.hub.poll_next(self.id, cx)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.hub.size_hint(self.id)
+	}
+}
+
+impl<Item> FusedStream for BroadcastCursor<'_, Item> {
+	fn is_terminated(&self) -> bool {
+		self.hub.is_terminated(self.id)
+	}
+}
+
+impl<Item> Drop for BroadcastCursor<'_, Item> {
+	fn drop(&mut self) {
+		self.hub.remove(self.id);
+	}
+}
+
+// region: shared state
+
+/// The object-safe interface through which [`BroadcastCursor`]s access their [`Broadcast`].
+trait Hub<Item> {
+	/// Adds a cursor at the position of `like`, or at the oldest buffered item.
+	fn insert(self: Pin<&Self>, like: Option<usize>) -> Option<usize>;
+	fn remove(self: Pin<&Self>, id: usize);
+	fn poll_peek_n(
+		self: Pin<&Self>,
+		id: usize,
+		depth: usize,
+		cx: &mut Context<'_>,
+	) -> Poll<Option<Item>>;
+	fn poll_next(self: Pin<&Self>, id: usize, cx: &mut Context<'_>) -> Poll<Option<Item>>;
+	fn size_hint(self: Pin<&Self>, id: usize) -> (usize, Option<usize>);
+	fn is_terminated(self: Pin<&Self>, id: usize) -> bool;
+}
+
+type ArrayShared<Input, const CAPACITY: usize, const CURSORS: usize> =
+	Shared<Input, ArrayRing<<Input as Stream>::Item, CAPACITY>, [Option<Slot>; CURSORS]>;
+
+#[cfg(feature = "alloc")]
+type VecShared<Input> = Shared<Input, Growable<<Input as Stream>::Item>, Vec<Option<Slot>>>;
+
+struct Slot {
+	/// Counted from the first item ever buffered.
+	position: usize,
+	waker: Option<Waker>,
+}
+
+trait Buffer<T> {
+	fn capacity(&self) -> usize;
+	fn len(&self) -> usize;
+	fn get(&self, index: usize) -> &T;
+	fn push_back(&mut self, item: T);
+	fn pop_front(&mut self) -> Option<T>;
+}
+
+trait Slots {
+	fn slots(&self) -> &[Option<Slot>];
+	fn slots_mut(&mut self) -> &mut [Option<Slot>];
+	fn insert(&mut self, slot: Slot) -> Option<usize>;
+}
+
+impl<T, const N: usize> Buffer<T> for ArrayRing<T, N> {
+	fn capacity(&self) -> usize {
+		N
+	}
+
+	fn len(&self) -> usize {
+		ArrayRing::len(self)
+	}
+
+	fn get(&self, index: usize) -> &T {
+		ArrayRing::get(self, index).expect("`index` out of range")
+	}
+
+	fn push_back(&mut self, item: T) {
+		ArrayRing::push_back(self, item).unwrap_or_else(|_| panic!("buffer is full"));
+	}
+
+	fn pop_front(&mut self) -> Option<T> {
+		ArrayRing::pop_front(self)
+	}
+}
+
+impl<const N: usize> Slots for [Option<Slot>; N] {
+	fn slots(&self) -> &[Option<Slot>] {
+		self
+	}
+
+	fn slots_mut(&mut self) -> &mut [Option<Slot>] {
+		self
+	}
+
+	fn insert(&mut self, slot: Slot) -> Option<usize> {
+		let (id, free) = self.iter_mut().enumerate().find(|(_, s)| s.is_none())?;
+		*free = Some(slot);
+		Some(id)
+	}
+}
+
+#[cfg(feature = "alloc")]
+struct Growable<T> {
+	items: VecDeque<T>,
+	capacity: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Buffer<T> for Growable<T> {
+	fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	fn len(&self) -> usize {
+		self.items.len()
+	}
+
+	fn get(&self, index: usize) -> &T {
+		&self.items[index]
+	}
+
+	fn push_back(&mut self, item: T) {
+		assert!(self.items.len() < self.capacity);
+		self.items.push_back(item);
+	}
+
+	fn pop_front(&mut self) -> Option<T> {
+		self.items.pop_front()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl Slots for Vec<Option<Slot>> {
+	fn slots(&self) -> &[Option<Slot>] {
+		self
+	}
+
+	fn slots_mut(&mut self) -> &mut [Option<Slot>] {
+		self
+	}
+
+	fn insert(&mut self, slot: Slot) -> Option<usize> {
+		if let Some((id, free)) = self.iter_mut().enumerate().find(|(_, s)| s.is_none()) {
+			*free = Some(slot);
+			Some(id)
+		} else {
+			self.push(Some(slot));
+			Some(self.len() - 1)
+		}
+	}
+}
+
+/// `state` is never borrowed while calling into `input` or waking a cursor, so that either can use the [`Broadcast`] again.
+/// Only polling a cursor that has to wait for `input` from within `input` itself panics.
+struct Shared<Input: FusedStream, B, S> {
+	input: RefCell<Input>,
+	state: RefCell<State<B, S>>,
+}
+
+struct State<B, S> {
+	buffer: B,
+	/// The position of the oldest buffered item.
+	base: usize,
+	slots: S,
+}
+
+impl<B, S: Slots> State<B, S> {
+	fn slot(&mut self, id: usize) -> &mut Slot {
+		self.slots.slots_mut()[id].as_mut().expect("unreachable")
+	}
+
+	fn register(&mut self, id: usize, cx: &mut Context<'_>) {
+		let waker = &mut self.slot(id).waker;
+		if !waker.as_ref().map_or(false, |w| w.will_wake(cx.waker())) {
+			*waker = Some(cx.waker().clone());
+		}
+	}
+
+	/// Takes the first waker at or after slot `from`, except for that of cursor `id`.
+	fn take_waker(&mut self, id: usize, from: usize) -> Option<(usize, Waker)> {
+		self.slots
+			.slots_mut()
+			.iter_mut()
+			.enumerate()
+			.skip(from)
+			.filter(|(i, _)| *i != id)
+			.find_map(|(i, slot)| Some((i, slot.as_mut()?.waker.take()?)))
+	}
+
+	/// Drops buffered items that no cursor can reach anymore.
+	///
+	/// Returns whether any were dropped.
+	fn trim<T>(&mut self) -> bool
+	where
+		B: Buffer<T>,
+	{
+		let min = self
+			.slots
+			.slots()
+			.iter()
+			.flatten()
+			.map(|slot| slot.position)
+			.min();
+		let mut trimmed = false;
+		if let Some(min) = min {
+			while self.base < min && self.buffer.pop_front().is_some() {
+				self.base += 1;
+				trimmed = true;
+			}
+		}
+		trimmed
+	}
+}
+
+impl<Input: FusedStream, B, S> Shared<Input, B, S>
+where
+	B: Buffer<Input::Item>,
+	S: Slots,
+{
+	fn new(input: Input, buffer: B, slots: S) -> Self {
+		Self {
+			input: input.into(),
+			state: State {
+				buffer,
+				base: 0,
+				slots,
+			}
+			.into(),
+		}
+	}
+
+	fn wake_others(&self, id: usize) {
+		let mut from = 0;
+		loop {
+			let taken = self.state.borrow_mut().take_waker(id, from);
+			match taken {
+				Some((i, waker)) => {
+					waker.wake();
+					from = i + 1;
+				}
+				None => break,
+			}
+		}
+	}
+
+	/// Buffers items until the one at `position` is available or `input` ends.
+	///
+	/// # Safety
+	///
+	/// `self` must be pinned.
+	unsafe fn poll_fill(&self, id: usize, position: usize, cx: &mut Context<'_>) -> Poll<bool> {
+		loop {
+			let terminated = self.input.borrow().is_terminated();
+			{
+				let mut state = self.state.borrow_mut();
+				if position < state.base + state.buffer.len() {
+					return Poll::Ready(true);
+				} else if terminated {
+					return Poll::Ready(false);
+				} else if state.buffer.len() == state.buffer.capacity() {
+					// Backpressure. The slowest cursor wakes this one once it advances.
+					state.register(id, cx);
+					return Poll::Pending;
+				}
+			}
+
+			let polled = Pin::new_unchecked(&mut *self.input.borrow_mut()).poll_next(cx);
+			match polled {
+				Poll::Ready(Some(item)) => {
+					self.state.borrow_mut().buffer.push_back(item);
+					self.wake_others(id);
+				}
+				Poll::Ready(None) => {
+					self.wake_others(id);
+					return Poll::Ready(false);
+				}
+				Poll::Pending => {
+					// `input` only wakes the latest task, so the others are woken once it makes progress.
+					self.state.borrow_mut().register(id, cx);
+					return Poll::Pending;
+				}
+			}
+		}
+	}
+}
+
+impl<Input: FusedStream, B, S> Hub<Input::Item> for Shared<Input, B, S>
+where
+	Input::Item: Clone,
+	B: Buffer<Input::Item>,
+	S: Slots,
+{
+	fn insert(self: Pin<&Self>, like: Option<usize>) -> Option<usize> {
+		let mut state = self.state.borrow_mut();
+		let position = match like {
+			Some(id) => state.slot(id).position,
+			None => state.base,
+		};
+		state.slots.insert(Slot {
+			position,
+			waker: None,
+		})
+	}
+
+	fn remove(self: Pin<&Self>, id: usize) {
+		let (waiting, trimmed) = {
+			let mut state = self.state.borrow_mut();
+			let slot = state.slots.slots_mut()[id].take().expect("unreachable");
+			(slot.waker.is_some(), state.trim())
+		};
+		// A removed cursor that was waiting may have been the one `input` would wake.
+		if waiting || trimmed {
+			self.wake_others(id);
+		}
+	}
+
+	fn poll_peek_n(
+		self: Pin<&Self>,
+		id: usize,
+		depth: usize,
+		cx: &mut Context<'_>,
+	) -> Poll<Option<Input::Item>> {
+		let position = {
+			let mut state = self.state.borrow_mut();
+			assert!(
+				depth <= state.buffer.capacity(),
+				"`depth` exceeds the `Broadcast`'s capacity"
+			);
+			state.slot(id).position + (depth - 1)
+		};
+		// Safety: `self` is pinned.
+		if ready!(unsafe { self.poll_fill(id, position, cx) }) {
+			let state = self.state.borrow();
+			Poll::Ready(Some(state.buffer.get(position - state.base).clone()))
+		} else {
+			Poll::Ready(None)
+		}
+	}
+
+	fn poll_next(self: Pin<&Self>, id: usize, cx: &mut Context<'_>) -> Poll<Option<Input::Item>> {
+		let position = self.state.borrow_mut().slot(id).position;
+		// Safety: `self` is pinned.
+		if !ready!(unsafe { self.poll_fill(id, position, cx) }) {
+			return Poll::Ready(None);
+		}
+
+		let mut state = self.state.borrow_mut();
+		state.slot(id).position += 1;
+		let last = position == state.base
+			&& state
+				.slots
+				.slots()
+				.iter()
+				.flatten()
+				.all(|slot| slot.position > position);
+		if last {
+			state.base += 1;
+			let item = state.buffer.pop_front().expect("unreachable");
+			drop(state);
+			self.wake_others(id);
+			Poll::Ready(Some(item))
+		} else {
+			Poll::Ready(Some(state.buffer.get(position - state.base).clone()))
+		}
+	}
+
+	fn size_hint(self: Pin<&Self>, id: usize) -> (usize, Option<usize>) {
+		let (start, end) = self.input.borrow().size_hint();
+		let mut state = self.state.borrow_mut();
+		let buffered = state.base + state.buffer.len() - state.slot(id).position;
+		(
+			start.saturating_add(buffered),
+			end.and_then(|end| end.checked_add(buffered)),
+		)
+	}
+
+	fn is_terminated(self: Pin<&Self>, id: usize) -> bool {
+		let terminated = self.input.borrow().is_terminated();
+		let mut state = self.state.borrow_mut();
+		state.slot(id).position == state.base + state.buffer.len() && terminated
+	}
+}
+
+// endregion
@@ -0,0 +1,330 @@
+use crate::util::array_from_fn;
+use core::{
+	fmt::{self, Debug, Formatter},
+	iter::FusedIterator,
+	mem::{self, ManuallyDrop, MaybeUninit},
+	ops::{Deref, DerefMut},
+	pin::Pin,
+	ptr, slice,
+	task::{Context, Poll},
+};
+use futures_core::{ready, FusedStream, Stream};
+use pin_project::pin_project;
+
+/// A [`Stream`] adapter that groups items into [`Chunk`]s of `N`, followed by one [`Chunk::Partial`] for any remainder.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::stream::{self, StreamExt as _};
+/// use percolate::stream::{chunks, Chunk};
+/// use pollster::block_on;
+///
+/// let chunks: Vec<Chunk<i32, 2>> = block_on(chunks::<_, 2>(stream::iter(1..=5).fuse()).collect());
+/// assert_eq!(chunks[0], Chunk::Full([1, 2]));
+/// assert_eq!(chunks[1], Chunk::Full([3, 4]));
+/// assert_eq!(*chunks[2], [5]);
+/// assert_eq!(chunks.len(), 3);
+/// ```
+#[pin_project]
+pub struct Chunks<Input: FusedStream, const N: usize> {
+	#[pin]
+	input: Input,
+	buffer: PartialChunk<Input::Item, N>,
+}
+
+impl<Input: FusedStream, const N: usize> Chunks<Input, N> {
+	/// # Panics
+	///
+	/// Iff `N` is 0.
+	#[must_use]
+	pub fn new(input: Input) -> Self {
+		assert!(N > 0, "`N` must be greater than 0");
+		Self {
+			input,
+			buffer: PartialChunk::new(),
+		}
+	}
+}
+
+impl<Input: FusedStream, const N: usize> Stream for Chunks<Input, N> {
+	type Item = Chunk<Input::Item, N>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let mut this = self.project();
+		loop {
+			let item = if this.input.is_terminated() {
+				None
+			} else {
+				ready!(this.input.as_mut().poll_next(cx))
+			};
+			match item {
+				Some(item) => {
+					this.buffer.push(item).unwrap_or_else(|_| {
+						unreachable!("`buffer` is emptied whenever it fills up")
+					});
+					if this.buffer.len() == N {
+						let full = mem::take(this.buffer)
+							.into_array()
+							.unwrap_or_else(|_| unreachable!());
+						return Poll::Ready(Some(Chunk::Full(full)));
+					}
+				}
+				None => {
+					return Poll::Ready(
+						(!this.buffer.is_empty()).then(|| Chunk::Partial(mem::take(this.buffer))),
+					)
+				}
+			}
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let buffered = self.buffer.len();
+		let (start, end) = self.input.size_hint();
+		let chunks = |items: usize| items / N + usize::from(items % N > 0);
+		(
+			chunks(start.saturating_add(buffered)),
+			end.and_then(|end| end.checked_add(buffered)).map(chunks),
+		)
+	}
+}
+
+impl<Input: FusedStream, const N: usize> FusedStream for Chunks<Input, N> {
+	fn is_terminated(&self) -> bool {
+		self.buffer.is_empty() && self.input.is_terminated()
+	}
+}
+
+/// [`FusedStream`] → [`Chunks`]
+///
+/// # Panics
+///
+/// Iff `N` is 0.
+#[must_use]
+pub fn chunks<Input: FusedStream, const N: usize>(input: Input) -> Chunks<Input, N> {
+	Chunks::new(input)
+}
+
+/// An item of [`Chunks`].
+///
+/// Dereferences to a slice of its items.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Chunk<T, const N: usize> {
+	/// A complete chunk.
+	Full([T; N]),
+	/// The remaining items at the end of the input, if there were fewer than `N`.
+	Partial(PartialChunk<T, N>),
+}
+
+impl<T, const N: usize> Chunk<T, N> {
+	/// Returns the items as array iff this is a [`Chunk::Full`].
+	pub fn into_full(self) -> Option<[T; N]> {
+		match self {
+			Chunk::Full(full) => Some(full),
+			Chunk::Partial(_) => None,
+		}
+	}
+}
+
+impl<T, const N: usize> Deref for Chunk<T, N> {
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target {
+		match self {
+			Chunk::Full(full) => full,
+			Chunk::Partial(partial) => partial,
+		}
+	}
+}
+
+impl<T, const N: usize> DerefMut for Chunk<T, N> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		match self {
+			Chunk::Full(full) => full,
+			Chunk::Partial(partial) => partial,
+		}
+	}
+}
+
+/// An inline vector of up to `N` items.
+///
+/// Dereferences to a slice of its items.
+pub struct PartialChunk<T, const N: usize> {
+	items: [MaybeUninit<T>; N],
+	len: usize,
+}
+
+impl<T, const N: usize> PartialChunk<T, N> {
+	/// Creates a new empty [`PartialChunk`].
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			items: array_from_fn(|_| MaybeUninit::uninit()),
+			len: 0,
+		}
+	}
+
+	/// Appends `item`, or returns it if `self` is already full.
+	///
+	/// # Errors
+	///
+	/// Iff `self` already contains `N` items.
+	pub fn push(&mut self, item: T) -> Result<(), T> {
+		if self.len < N {
+			self.items[self.len] = MaybeUninit::new(item);
+			self.len += 1;
+			Ok(())
+		} else {
+			Err(item)
+		}
+	}
+
+	/// Removes and returns the last item, if any.
+	pub fn pop(&mut self) -> Option<T> {
+		if self.len > 0 {
+			self.len -= 1;
+			Some(unsafe {
+				// Safety: Initialised, and marked as uninitialised directly above.
+				ptr::read(self.items[self.len].as_ptr())
+			})
+		} else {
+			None
+		}
+	}
+
+	/// Converts `self` into an array iff it is full.
+	///
+	/// # Errors
+	///
+	/// Iff `self` contains fewer than `N` items, in which case it is returned unchanged.
+	pub fn into_array(self) -> Result<[T; N], Self> {
+		if self.len == N {
+			let this = ManuallyDrop::new(self);
+			Ok(unsafe {
+				// Safety: Fully initialised, and `this` isn't dropped.
+				ptr::read(this.items.as_ptr().cast::<[T; N]>())
+			})
+		} else {
+			Err(self)
+		}
+	}
+}
+
+impl<T, const N: usize> Default for PartialChunk<T, N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, const N: usize> Deref for PartialChunk<T, N> {
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target {
+		unsafe {
+			// Safety: The first `len` items are initialised.
+			slice::from_raw_parts(self.items.as_ptr().cast::<T>(), self.len)
+		}
+	}
+}
+
+impl<T, const N: usize> DerefMut for PartialChunk<T, N> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		unsafe {
+			// Safety: The first `len` items are initialised.
+			slice::from_raw_parts_mut(self.items.as_mut_ptr().cast::<T>(), self.len)
+		}
+	}
+}
+
+impl<T, const N: usize> Drop for PartialChunk<T, N> {
+	fn drop(&mut self) {
+		let items: *mut [T] = &mut **self;
+		self.len = 0;
+		unsafe {
+			// Safety: These were initialised and are now marked as uninitialised.
+			ptr::drop_in_place(items);
+		}
+	}
+}
+
+impl<T: Debug, const N: usize> Debug for PartialChunk<T, N> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for PartialChunk<T, N> {
+	fn eq(&self, other: &Self) -> bool {
+		**self == **other
+	}
+}
+impl<T: Eq, const N: usize> Eq for PartialChunk<T, N> {}
+
+impl<T, const N: usize> IntoIterator for PartialChunk<T, N> {
+	type Item = T;
+	type IntoIter = PartialChunkIntoIter<T, N>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		let this = ManuallyDrop::new(self);
+		PartialChunkIntoIter {
+			items: unsafe {
+				// Safety: `this` isn't dropped, so ownership of the items is transferred.
+				ptr::read(&this.items)
+			},
+			start: 0,
+			end: this.len,
+		}
+	}
+}
+
+/// The owning [`Iterator`] of a [`PartialChunk`].
+pub struct PartialChunkIntoIter<T, const N: usize> {
+	items: [MaybeUninit<T>; N],
+	start: usize,
+	end: usize,
+}
+
+impl<T, const N: usize> Iterator for PartialChunkIntoIter<T, N> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.start < self.end {
+			self.start += 1;
+			Some(unsafe {
+				// Safety: Initialised, and marked as uninitialised directly above.
+				ptr::read(self.items[self.start - 1].as_ptr())
+			})
+		} else {
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.end - self.start;
+		(len, Some(len))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for PartialChunkIntoIter<T, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.start < self.end {
+			self.end -= 1;
+			Some(unsafe {
+				// Safety: Initialised, and marked as uninitialised directly above.
+				ptr::read(self.items[self.end].as_ptr())
+			})
+		} else {
+			None
+		}
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for PartialChunkIntoIter<T, N> {}
+impl<T, const N: usize> FusedIterator for PartialChunkIntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for PartialChunkIntoIter<T, N> {
+	fn drop(&mut self) {
+		for _ in self {}
+	}
+}
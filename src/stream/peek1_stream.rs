@@ -0,0 +1,192 @@
+use crate::{
+	predicate::{IntoMutPredicateMut, IntoPredicateMut, MutPredicateMut, PredicateMut},
+	projection::{IntoMutProjectionMut, ProjectionMut},
+};
+use core::{
+	pin::Pin,
+	task::{Context, Poll},
+};
+use ergo_pin::ergo_pin;
+use futures_core::{FusedStream, Stream};
+use futures_util::StreamExt as _;
+use pin_project::pin_project;
+
+/// A single-item lookahead [`Stream`] adapter.
+///
+/// This behaves like [`PeekStream<Input, 1>`](`super::PeekStream`), but stores the peeked item in an [`Option`],
+/// which avoids the ring buffer index arithmetic.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use futures_util::stream::{self, StreamExt as _};
+/// use percolate::{predicate::ops::lt, stream::Peek1Stream};
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn leading_small(input: &[u8]) -> usize {
+///     let mut stream = pin!(Peek1Stream::new(stream::iter(input.iter().copied()).fuse()));
+///     let mut count = 0;
+///     while stream.as_mut().next_if(lt(10)).await.is_some() {
+///         count += 1;
+///     }
+///     assert_eq!(stream.as_mut().peek().await.is_some(), count < input.len());
+///     count
+/// }
+///
+/// assert_eq!(block_on(leading_small(&[1, 2, 30, 4])), 2);
+/// assert_eq!(block_on(leading_small(&[1, 2])), 2);
+/// ```
+#[pin_project]
+pub struct Peek1Stream<Input: FusedStream> {
+	#[pin]
+	input: Input,
+	peeked: Option<Input::Item>,
+}
+
+impl<Input: FusedStream> Stream for Peek1Stream<Input> {
+	type Item = Input::Item;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.project();
+		match this.peeked.take() {
+			Some(item) => Poll::Ready(Some(item)),
+			None => this.input.poll_next(cx),
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let peeked = usize::from(self.peeked.is_some());
+		let (start, end) = self.input.size_hint();
+		(
+			start.saturating_add(peeked),
+			end.and_then(|end| end.checked_add(peeked)),
+		)
+	}
+}
+
+impl<Input: FusedStream> FusedStream for Peek1Stream<Input> {
+	fn is_terminated(&self) -> bool {
+		self.peeked.is_none() && self.input.is_terminated()
+	}
+}
+
+impl<Input: FusedStream> Peek1Stream<Input> {
+	/// Creates a new [`Peek1Stream`] with nothing peeked yet.
+	#[must_use]
+	pub fn new(input: Input) -> Self {
+		Self {
+			input,
+			peeked: None,
+		}
+	}
+
+	/// Peeks the next item in `self`.
+	pub async fn peek(self: Pin<&mut Self>) -> Option<&Input::Item> {
+		self.peek_mut().await.map(|item| &*item)
+	}
+
+	/// Peeks the next item in `self`,
+	/// allowing the caller to mutate it if available.
+	pub async fn peek_mut(self: Pin<&mut Self>) -> Option<&mut Input::Item> {
+		let mut this = self.project();
+		if this.peeked.is_none() && !this.input.is_terminated() {
+			*this.peeked = this.input.next().await;
+		}
+		this.peeked.as_mut()
+	}
+
+	/// Retrieves the next item only if it satisfies `predicate`.
+	///
+	/// * The conversion of `predicate` happens immediately.
+	/// * Buffers the next item, if available.
+	#[ergo_pin]
+	pub async fn next_if<X>(
+		mut self: Pin<&mut Self>,
+		predicate: impl IntoPredicateMut<Input::Item, X>,
+	) -> Option<Input::Item> {
+		if pin!(predicate.into_predicate_mut())
+			.test(self.as_mut().peek().await?)
+			.await
+		{
+			self.project().peeked.take()
+		} else {
+			None
+		}
+	}
+
+	/// Retrieves the next item only if it is equal to `expected`.
+	///
+	/// Buffers the next item, if available.
+	pub async fn next_if_eq<T: ?Sized>(
+		mut self: Pin<&mut Self>,
+		expected: &T,
+	) -> Option<Input::Item>
+	where
+		Input::Item: PartialEq<T>,
+	{
+		if self.as_mut().peek().await? == expected {
+			self.project().peeked.take()
+		} else {
+			None
+		}
+	}
+
+	/// Retrieves the next item only if it satisfies `predicate`,
+	/// optionally mutating it during the check.
+	///
+	/// * The conversion of `predicate` happens immediately.
+	/// * Buffers the next item, if available.
+	#[ergo_pin]
+	pub async fn next_if_mut<X>(
+		mut self: Pin<&mut Self>,
+		predicate: impl IntoMutPredicateMut<Input::Item, X>,
+	) -> Option<Input::Item> {
+		if pin!(predicate.into_mut_predicate_mut())
+			.test_mut(self.as_mut().peek_mut().await?)
+			.await
+		{
+			self.project().peeked.take()
+		} else {
+			None
+		}
+	}
+
+	/// Retrieves the next item through `projection`, only consuming it if the result is [`Some`].
+	///
+	/// The projection may mutate the item, which is then retained if the result is [`None`].
+	///
+	/// * The conversion of `projection` happens immediately.
+	/// * Buffers the next item, if available.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ergo_pin::ergo_pin;
+	/// use futures_util::stream::{self, StreamExt as _};
+	/// use percolate::stream::Peek1Stream;
+	/// use pollster::block_on;
+	///
+	/// #[ergo_pin]
+	/// async fn leading_digit(input: &str) -> (Option<u32>, Option<char>) {
+	///     let mut stream = pin!(Peek1Stream::new(stream::iter(input.chars()).fuse()));
+	///     let digit = stream.as_mut().next_if_map(|c: &mut char| c.to_digit(10)).await;
+	///     (digit, stream.next().await)
+	/// }
+	///
+	/// assert_eq!(block_on(leading_digit("7a")), (Some(7), Some('a')));
+	/// assert_eq!(block_on(leading_digit("a7")), (None, Some('a')));
+	/// ```
+	#[ergo_pin]
+	pub async fn next_if_map<U, X>(
+		mut self: Pin<&mut Self>,
+		projection: impl IntoMutProjectionMut<Input::Item, Option<U>, X>,
+	) -> Option<U> {
+		let mapped = pin!(projection.into_mut_projection_mut())
+			.project(self.as_mut().peek_mut().await?)
+			.await?;
+		self.project().peeked.take();
+		Some(mapped)
+	}
+}
@@ -1,9 +1,10 @@
-use crate::predicate::{IntoMutPredicateMut, IntoPredicateMut, MutPredicateMut, PredicateMut};
+use crate::{
+	predicate::{IntoMutPredicateMut, IntoPredicateMut, MutPredicateMut, PredicateMut},
+	projection::{IntoMutProjectionMut, IntoRefProjectionMut, ProjectionMut, RefProjectionMut},
+	ring::ArrayRing,
+};
 use core::{
-	convert::TryFrom,
-	mem::MaybeUninit,
 	num::NonZeroUsize,
-	ops::{Add, AddAssign, Sub},
 	pin::Pin,
 	task::{Context, Poll},
 };
@@ -11,130 +12,67 @@ use ergo_pin::ergo_pin;
 use futures_core::{FusedStream, Stream};
 use futures_util::StreamExt as _;
 use pin_project::pin_project;
-use tap::{Conv as _, Pipe as _};
-
-// A neat generic implementation isn't yet possible because types of const generic parameters can't depend on other type parameters yet.
-// TODO: Check maths terms.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Modular<const MODULE: usize>(pub usize);
-impl<const MODULE: usize> From<Modular<MODULE>> for usize {
-	fn from(modular: Modular<MODULE>) -> Self {
-		modular.0
-	}
-}
-impl<const MODULE: usize> From<&mut Modular<MODULE>> for usize {
-	fn from(modular: &mut Modular<MODULE>) -> Self {
-		modular.0
-	}
-}
-impl<const MODULE: usize> TryFrom<usize> for Modular<MODULE> {
-	type Error = ();
-
-	fn try_from(linear: usize) -> Result<Self, ()> {
-		(linear < MODULE).then(|| Modular(linear)).ok_or(())
-	}
-}
-impl<const MODULE: usize> Sub for Modular<MODULE> {
-	type Output = usize;
-
-	fn sub(self, rhs: Self) -> Self::Output {
-		if self.0 >= rhs.0 {
-			self.0 - rhs.0
-		} else {
-			MODULE - rhs.0 + self.0
-		}
-	}
-}
-impl<Rhs, const MODULE: usize> Sub<&Rhs> for Modular<MODULE>
-where
-	Self: Sub<Rhs>,
-	Rhs: Copy,
-{
-	type Output = <Self as Sub<Rhs>>::Output;
-
-	fn sub(self, rhs: &Rhs) -> Self::Output {
-		self - *rhs
-	}
-}
-impl<Rhs, const MODULE: usize> Sub<Rhs> for &Modular<MODULE>
-where
-	Modular<MODULE>: Sub<Rhs>,
-{
-	type Output = <Modular<MODULE> as Sub<Rhs>>::Output;
-
-	fn sub(self, rhs: Rhs) -> Self::Output {
-		*self - rhs
-	}
-}
-impl<const MODULE: usize> Add<usize> for Modular<MODULE> {
-	type Output = Self;
-
-	fn add(self, rhs: usize) -> Self::Output {
-		Modular(
-			self.0
-				.checked_add(rhs % MODULE)
-				.expect("`Module` overflow in `add_assign`")
-				% MODULE,
-		)
-	}
-}
-impl<const MODULE: usize> Add<usize> for &Modular<MODULE> {
-	type Output = Modular<MODULE>;
-
-	fn add(self, rhs: usize) -> Self::Output {
-		*self + rhs
-	}
-}
-impl<const MODULE: usize> AddAssign<usize> for Modular<MODULE> {
-	fn add_assign(&mut self, rhs: usize) {
-		*self = *self + rhs;
-	}
-}
+use tap::Pipe as _;
 
 /// A fixed-size-buffered lookahead [`Stream`] adapter.
 #[pin_project]
 pub struct PeekStream<Input: FusedStream, const CAPACITY: usize> {
 	#[pin]
 	input: Input,
-	buffer: [MaybeUninit<Input::Item>; CAPACITY],
-	start: Modular<CAPACITY>,
-	len: usize,
+	buffer: ArrayRing<Input::Item, CAPACITY>,
 }
+
 impl<Input: FusedStream, const CAPACITY: usize> Stream for PeekStream<Input, CAPACITY> {
 	type Item = Input::Item;
 
 	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
 		let this = self.project();
-		if *this.len > 0 {
-			let i: usize = this.start.into();
-			*this.start += 1;
-			*this.len -= 1;
-			unsafe { this.buffer[i].as_ptr().read() }
-				.pipe(Some)
-				.pipe(Poll::Ready)
-		} else {
-			this.input.poll_next(cx)
+		match this.buffer.pop_front() {
+			Some(item) => Poll::Ready(Some(item)),
+			None => this.input.poll_next(cx),
 		}
 	}
 
 	fn size_hint(&self) -> (usize, Option<usize>) {
+		let buffered = self.buffer.len();
 		let (start, end) = self.input.size_hint();
 		(
-			start + self.len,
-			end.and_then(|end| end.checked_add(self.len)),
+			start.saturating_add(buffered),
+			end.and_then(|end| end.checked_add(buffered)),
 		)
 	}
 }
 impl<Input: FusedStream, const CAPACITY: usize> FusedStream for PeekStream<Input, CAPACITY> {
 	fn is_terminated(&self) -> bool {
-		self.len == 0 && self.input.is_terminated()
+		self.buffer.is_empty() && self.input.is_terminated()
 	}
 }
 impl<Input: FusedStream, const CAPACITY: usize> PeekStream<Input, CAPACITY> {
+	/// Creates a new [`PeekStream`] with an initially empty lookahead buffer.
+	#[must_use]
+	pub fn new(input: Input) -> Self {
+		Self {
+			input,
+			buffer: ArrayRing::new(),
+		}
+	}
+
+	/// Peeks the next item in `self`.
+	///
+	/// # Panics
+	///
+	/// Iff `CAPACITY` is 0.
 	pub async fn peek_1(self: Pin<&mut Self>) -> Option<&Input::Item> {
 		self.peek_n(NonZeroUsize::new(1).expect("unreachable"))
 			.await
 	}
+
+	/// Peeks the next item in `self`,
+	/// allowing the caller to mutate it if available.
+	///
+	/// # Panics
+	///
+	/// Iff `CAPACITY` is 0.
 	pub async fn peek_1_mut(self: Pin<&mut Self>) -> Option<&mut Input::Item> {
 		self.peek_n_mut(NonZeroUsize::new(1).expect("unreachable"))
 			.await
@@ -161,20 +99,92 @@ impl<Input: FusedStream, const CAPACITY: usize> PeekStream<Input, CAPACITY> {
 			"`depth` out of range `0..CAPACITY`"
 		);
 		let mut this = self.project();
-		while *this.len < depth.get() {
+		while this.buffer.len() < depth.get() {
 			if this.input.is_terminated() {
 				return None;
 			} else {
-				this.buffer[(*this.start + *this.len).conv::<usize>()] =
-					this.input.next().await?.pipe(MaybeUninit::new);
-				*this.len += 1;
+				let item = this.input.next().await?;
+				this.buffer
+					.push_back(item)
+					.unwrap_or_else(|_| unreachable!("`depth` is at most `CAPACITY`"));
 			}
 		}
-		unsafe {
-			// Safety: Assuredly written to directly above or earlier than that.
-			&mut *this.buffer[(*this.start + depth.get()).conv::<usize>()].as_mut_ptr()
+		this.buffer.get_mut(depth.get() - 1)
+	}
+
+	/// Runs `projection` over the item `depth` items ahead in `self`, without consuming it.
+	///
+	/// * The conversion of `projection` happens immediately.
+	/// * Buffers up to `depth` items, if available.
+	///
+	/// # Panics
+	///
+	/// Iff `depth` exceeds `CAPACITY`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use core::num::NonZeroUsize;
+	/// use futures_util::stream::{self, StreamExt as _};
+	/// use percolate::stream::PeekStream;
+	/// use pollster::block_on;
+	///
+	/// let mut stream = Box::pin(PeekStream::<_, 2>::new(stream::iter("a7".chars()).fuse()));
+	/// let second = NonZeroUsize::new(2).unwrap();
+	/// let digit = block_on(stream.as_mut().peek_project(second, |c: &char| c.to_digit(10)));
+	/// assert_eq!(digit, Some(Some(7)));
+	/// assert_eq!(block_on(stream.next()), Some('a'));
+	/// ```
+	#[ergo_pin]
+	pub async fn peek_project<B, X>(
+		mut self: Pin<&mut Self>,
+		depth: NonZeroUsize,
+		projection: impl IntoRefProjectionMut<Input::Item, B, X>,
+	) -> Option<B> {
+		pin!(projection.into_ref_projection_mut())
+			.project_ref(self.as_mut().peek_n(depth).await?)
+			.await
+			.pipe(Some)
+	}
+
+	/// Finds the depth of the first item within the next `CAPACITY` that satisfies `predicate`, without consuming any.
+	///
+	/// The result can be passed on to [`.peek_n(…)`](`PeekStream::peek_n`) and similar.
+	///
+	/// * The conversion of `predicate` happens immediately.
+	/// * Buffers items only until one matches, up to `CAPACITY`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use futures_util::stream::{self, StreamExt as _};
+	/// use percolate::stream::PeekStream;
+	/// use pollster::block_on;
+	///
+	/// let mut stream = Box::pin(PeekStream::<_, 4>::new(stream::iter("ab;cd;".chars()).fuse()));
+	/// let semicolon = block_on(stream.as_mut().peek_position(|c: &char| *c == ';'));
+	/// assert_eq!(semicolon.map(|depth| depth.get()), Some(3));
+	///
+	/// // `'d'` is out of reach:
+	/// assert_eq!(block_on(stream.as_mut().peek_position(|c: &char| *c == 'd')), None);
+	/// assert_eq!(block_on(stream.next()), Some('a'));
+	/// ```
+	#[ergo_pin]
+	pub async fn peek_position<X>(
+		mut self: Pin<&mut Self>,
+		predicate: impl IntoPredicateMut<Input::Item, X>,
+	) -> Option<NonZeroUsize> {
+		let mut predicate = pin!(predicate.into_predicate_mut());
+		for depth in (1..=CAPACITY).filter_map(NonZeroUsize::new) {
+			if predicate
+				.as_mut()
+				.test(self.as_mut().peek_n(depth).await?)
+				.await
+			{
+				return Some(depth);
+			}
 		}
-		.pipe(Some)
+		None
 	}
 
 	/// Retrieves the next item only if it satisfies `predicate`.
@@ -215,4 +225,58 @@ impl<Input: FusedStream, const CAPACITY: usize> PeekStream<Input, CAPACITY> {
 			None
 		}
 	}
+
+	/// Retrieves the next item only if it is equal to `expected`.
+	///
+	/// Buffers the next item, if available.
+	pub async fn next_if_eq<T: ?Sized>(
+		mut self: Pin<&mut Self>,
+		expected: &T,
+	) -> Option<Input::Item>
+	where
+		Input::Item: PartialEq<T>,
+	{
+		if self.as_mut().peek_1().await? == expected {
+			self.next().await
+		} else {
+			None
+		}
+	}
+
+	/// Retrieves the next item through `projection`, only consuming it if the result is [`Some`].
+	///
+	/// The projection may mutate the item, which is then retained if the result is [`None`].
+	///
+	/// * The conversion of `projection` happens immediately.
+	/// * Buffers the next item, if available.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ergo_pin::ergo_pin;
+	/// use futures_util::stream::{self, StreamExt as _};
+	/// use percolate::stream::PeekStream;
+	/// use pollster::block_on;
+	///
+	/// #[ergo_pin]
+	/// async fn leading_digit(input: &str) -> (Option<u32>, Option<char>) {
+	///     let mut stream = pin!(PeekStream::<_, 1>::new(stream::iter(input.chars()).fuse()));
+	///     let digit = stream.as_mut().next_if_map(|c: &mut char| c.to_digit(10)).await;
+	///     (digit, stream.next().await)
+	/// }
+	///
+	/// assert_eq!(block_on(leading_digit("7a")), (Some(7), Some('a')));
+	/// assert_eq!(block_on(leading_digit("a7")), (None, Some('a')));
+	/// ```
+	#[ergo_pin]
+	pub async fn next_if_map<U, X>(
+		mut self: Pin<&mut Self>,
+		projection: impl IntoMutProjectionMut<Input::Item, Option<U>, X>,
+	) -> Option<U> {
+		let mapped = pin!(projection.into_mut_projection_mut())
+			.project(self.as_mut().peek_1_mut().await?)
+			.await?;
+		self.next().await;
+		Some(mapped)
+	}
 }
@@ -0,0 +1,194 @@
+use super::PeekStream;
+use core::{
+	num::NonZeroUsize,
+	ops::Range,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use futures_core::{FusedStream, Stream};
+use pin_project::pin_project;
+
+/// A position in a sequence of `Item`s, which can be advanced past each of them.
+pub trait Position<Item: ?Sized>: Clone {
+	/// Moves `self` from the start of `item` to just after it.
+	fn advance(&mut self, item: &Item);
+
+	/// The position just after `item`, if `self` is where it starts.
+	#[must_use]
+	fn after(&self, item: &Item) -> Self {
+		let mut after = self.clone();
+		after.advance(item);
+		after
+	}
+}
+
+/// Counts items, regardless of their type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItemIndex(pub usize);
+impl<Item: ?Sized> Position<Item> for ItemIndex {
+	fn advance(&mut self, _: &Item) {
+		self.0 += 1;
+	}
+}
+
+/// Counts bytes, with [`char`]s measured in UTF-8.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteOffset(pub usize);
+impl Position<u8> for ByteOffset {
+	fn advance(&mut self, _: &u8) {
+		self.0 += 1;
+	}
+}
+impl Position<char> for ByteOffset {
+	fn advance(&mut self, item: &char) {
+		self.0 += item.len_utf8();
+	}
+}
+
+/// A 1-based line and column (in [`char`]s), as usually shown by text editors.
+///
+/// Only `'\n'` starts a new line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineColumn {
+	/// Starts at 1.
+	pub line: usize,
+	/// Starts at 1 on each line.
+	pub column: usize,
+}
+impl Default for LineColumn {
+	fn default() -> Self {
+		Self { line: 1, column: 1 }
+	}
+}
+impl Position<char> for LineColumn {
+	fn advance(&mut self, item: &char) {
+		if *item == '\n' {
+			self.line += 1;
+			self.column = 1;
+		} else {
+			self.column += 1;
+		}
+	}
+}
+
+/// A [`Stream`] adapter that pairs each item with the [`Position`] at which it starts.
+///
+/// Wrap it in a [`PeekStream`] to look up the [span](`PeekStream::peek_n_span`) of lookahead items.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::stream::{self, StreamExt as _};
+/// use percolate::stream::{LineColumn, Positioned};
+/// use pollster::block_on;
+///
+/// let positioned = Positioned::<_, LineColumn>::new(stream::iter("a\nb".chars()));
+/// assert_eq!(
+///     block_on(positioned.map(|(position, _)| (position.line, position.column)).collect::<Vec<_>>()),
+///     [(1, 1), (1, 2), (2, 1)],
+/// );
+/// ```
+#[pin_project]
+pub struct Positioned<Input: Stream, Pos: Position<Input::Item>> {
+	#[pin]
+	input: Input,
+	position: Pos,
+}
+
+impl<Input: Stream, Pos: Position<Input::Item>> Positioned<Input, Pos> {
+	/// Creates a new [`Positioned`] that starts counting at [`Pos::default()`](`Default::default`).
+	#[must_use]
+	pub fn new(input: Input) -> Self
+	where
+		Pos: Default,
+	{
+		Self::with_start(input, Pos::default())
+	}
+
+	/// Creates a new [`Positioned`] that starts counting at `start`.
+	#[must_use]
+	pub fn with_start(input: Input, start: Pos) -> Self {
+		Self {
+			input,
+			position: start,
+		}
+	}
+
+	/// The position of the next item from `input`.
+	#[must_use]
+	pub fn position(&self) -> &Pos {
+		&self.position
+	}
+
+	/// Discards the position and returns the wrapped [`Stream`].
+	#[must_use]
+	pub fn into_inner(self) -> Input {
+		self.input
+	}
+}
+
+impl<Input: Stream, Pos: Position<Input::Item>> Stream for Positioned<Input, Pos> {
+	type Item = (Pos, Input::Item);
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.project();
+		let position = this.position;
+		this.input.poll_next(cx).map(|item| {
+			item.map(|item| {
+				let start = position.clone();
+				position.advance(&item);
+				(start, item)
+			})
+		})
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.input.size_hint()
+	}
+}
+
+impl<Input: FusedStream, Pos: Position<Input::Item>> FusedStream for Positioned<Input, Pos> {
+	fn is_terminated(&self) -> bool {
+		self.input.is_terminated()
+	}
+}
+
+impl<Input, Pos, const CAPACITY: usize> PeekStream<Positioned<Input, Pos>, CAPACITY>
+where
+	Input: FusedStream,
+	Pos: Position<Input::Item>,
+{
+	/// Peeks `depth` items ahead in `self`, along with the span from the peeked item's start to its end.
+	///
+	/// # Panics
+	///
+	/// Iff `depth` exceeds `CAPACITY`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use core::num::NonZeroUsize;
+	/// use ergo_pin::ergo_pin;
+	/// use futures_util::stream::{self, StreamExt as _};
+	/// use percolate::stream::{ByteOffset, PeekStream, Positioned};
+	/// use pollster::block_on;
+	///
+	/// #[ergo_pin]
+	/// async fn second_span(input: &str) -> Option<(usize, usize)> {
+	///     let stream = pin!(PeekStream::<_, 2>::new(Positioned::<_, ByteOffset>::new(
+	///         stream::iter(input.chars()).fuse(),
+	///     )));
+	///     let (span, _) = stream.peek_n_span(NonZeroUsize::new(2).unwrap()).await?;
+	///     Some((span.start.0, span.end.0))
+	/// }
+	///
+	/// assert_eq!(block_on(second_span("aäb")), Some((1, 3)));
+	/// ```
+	pub async fn peek_n_span(
+		self: Pin<&mut Self>,
+		depth: NonZeroUsize,
+	) -> Option<(Range<Pos>, &Input::Item)> {
+		let (start, item) = self.peek_n(depth).await?;
+		Some((start.clone()..start.after(item), item))
+	}
+}
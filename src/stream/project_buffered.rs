@@ -0,0 +1,325 @@
+use crate::{handles::ErasedFutureHandleMut, projection::ProjectionMut, util::array_from_fn};
+use core::{
+	mem,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use futures_core::{FusedStream, Stream};
+use pin_project::pin_project;
+
+enum Slot<B> {
+	Empty,
+	Running(usize, ErasedFutureHandleMut<B>),
+	Ready(usize, B),
+}
+
+/// Shared implementation of [`ProjectBuffered`] and [`ProjectBufferUnordered`].
+#[pin_project(!Unpin)]
+struct Pool<Input, P, B, const N: usize>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	// Declared before `projections` so that running futures are dropped first.
+	slots: [Slot<B>; N],
+	#[pin]
+	input: Input,
+	#[pin]
+	projections: [P; N],
+	next_input: usize,
+	next_output: usize,
+}
+
+impl<Input, P, B, const N: usize> Pool<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	fn new(input: Input, projections: [P; N]) -> Self {
+		assert!(N > 0, "Can't project anything without projections.");
+		Self {
+			slots: array_from_fn(|_| Slot::Empty),
+			input,
+			projections,
+			next_input: 0,
+			next_output: 0,
+		}
+	}
+
+	fn occupied(&self) -> usize {
+		self.slots
+			.iter()
+			.filter(|slot| !matches!(slot, Slot::Empty))
+			.count()
+	}
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>, ordered: bool) -> Poll<Option<B>> {
+		let mut this = self.project();
+		let projections = unsafe { this.projections.get_unchecked_mut() }.as_mut_ptr();
+
+		for (i, slot) in this.slots.iter_mut().enumerate() {
+			if !matches!(slot, Slot::Empty) {
+				continue;
+			}
+			if this.input.is_terminated() {
+				break;
+			}
+			match this.input.as_mut().poll_next(cx) {
+				Poll::Ready(Some(value)) => {
+					let future =
+						unsafe { Pin::new_unchecked(&mut *projections.add(i)) }.project(value);
+					*slot = Slot::Running(*this.next_input, unsafe {
+						// Safety: Dropped before `projections`, or by replacing `slot` before the projection is reused.
+						ErasedFutureHandleMut::new(future)
+					});
+					*this.next_input += 1;
+				}
+				Poll::Ready(None) | Poll::Pending => break,
+			}
+		}
+
+		for slot in this.slots.iter_mut() {
+			if let Slot::Running(index, future) = slot {
+				if let Poll::Ready(output) = future.poll(cx) {
+					if ordered {
+						*slot = Slot::Ready(*index, output);
+					} else {
+						*slot = Slot::Empty;
+						return Poll::Ready(Some(output));
+					}
+				}
+			}
+		}
+
+		for slot in this.slots.iter_mut() {
+			if matches!(slot, Slot::Ready(index, _) if *index == *this.next_output) {
+				*this.next_output += 1;
+				match mem::replace(slot, Slot::Empty) {
+					Slot::Ready(_, output) => return Poll::Ready(Some(output)),
+					_ => unreachable!(),
+				}
+			}
+		}
+
+		if this.input.is_terminated() && this.slots.iter().all(|slot| matches!(slot, Slot::Empty)) {
+			Poll::Ready(None)
+		} else {
+			Poll::Pending
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let occupied = self.occupied();
+		let (start, end) = self.input.size_hint();
+		(
+			start.saturating_add(occupied),
+			end.and_then(|end| end.checked_add(occupied)),
+		)
+	}
+
+	fn is_terminated(&self) -> bool {
+		self.input.is_terminated() && self.occupied() == 0
+	}
+}
+
+/// A [`Stream`] that projects the items of `Input` through up to `N` pinned projections at once,
+/// yielding the outputs in input order.
+///
+/// At most `N` outputs are buffered while waiting for an earlier one.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::{stream, StreamExt as _};
+/// use percolate::{projection::from_blocking_mut, stream::project_buffered};
+/// use pollster::block_on;
+///
+/// let double = || from_blocking_mut(|x: u32| x * 2);
+/// let doubled = project_buffered(stream::iter(1..=5).fuse(), [double(), double()]);
+///
+/// assert_eq!(block_on(doubled.collect::<Vec<_>>()), [2, 4, 6, 8, 10]);
+/// ```
+#[pin_project(!Unpin)]
+pub struct ProjectBuffered<Input, P, B, const N: usize>(#[pin] Pool<Input, P, B, N>)
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>;
+
+impl<Input, P, B, const N: usize> ProjectBuffered<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	/// # Panics
+	///
+	/// Iff `N` is 0.
+	#[must_use]
+	pub fn new(input: Input, projections: [P; N]) -> Self {
+		Self(Pool::new(input, projections))
+	}
+
+	/// Creates `N` projections through `factory`.
+	///
+	/// # Panics
+	///
+	/// Iff `N` is 0.
+	#[must_use]
+	pub fn from_fn(input: Input, mut factory: impl FnMut() -> P) -> Self {
+		Self::new(input, array_from_fn(|_| factory()))
+	}
+}
+
+impl<Input, P, B, const N: usize> Stream for ProjectBuffered<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	type Item = B;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.project().0.poll_next(cx, true)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<Input, P, B, const N: usize> FusedStream for ProjectBuffered<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	fn is_terminated(&self) -> bool {
+		self.0.is_terminated()
+	}
+}
+
+/// A [`Stream`] that projects the items of `Input` through up to `N` pinned projections at once,
+/// yielding the outputs in completion order.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::{stream, StreamExt as _};
+/// use percolate::{projection::from_blocking_mut, stream::project_buffer_unordered};
+/// use pollster::block_on;
+///
+/// let double = || from_blocking_mut(|x: u32| x * 2);
+/// let doubled = project_buffer_unordered(stream::iter(1..=5).fuse(), [double(), double()]);
+///
+/// let mut doubled = block_on(doubled.collect::<Vec<_>>());
+/// doubled.sort_unstable();
+/// assert_eq!(doubled, [2, 4, 6, 8, 10]);
+/// ```
+#[pin_project(!Unpin)]
+pub struct ProjectBufferUnordered<Input, P, B, const N: usize>(#[pin] Pool<Input, P, B, N>)
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>;
+
+impl<Input, P, B, const N: usize> ProjectBufferUnordered<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	/// # Panics
+	///
+	/// Iff `N` is 0.
+	#[must_use]
+	pub fn new(input: Input, projections: [P; N]) -> Self {
+		Self(Pool::new(input, projections))
+	}
+
+	/// Creates `N` projections through `factory`.
+	///
+	/// # Panics
+	///
+	/// Iff `N` is 0.
+	#[must_use]
+	pub fn from_fn(input: Input, mut factory: impl FnMut() -> P) -> Self {
+		Self::new(input, array_from_fn(|_| factory()))
+	}
+}
+
+impl<Input, P, B, const N: usize> Stream for ProjectBufferUnordered<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	type Item = B;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.project().0.poll_next(cx, false)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<Input, P, B, const N: usize> FusedStream for ProjectBufferUnordered<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	fn is_terminated(&self) -> bool {
+		self.0.is_terminated()
+	}
+}
+
+/// Projects the items of `input` through `projection` one at a time, yielding outputs in input order.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::{stream, StreamExt as _};
+/// use percolate::{projection::from_blocking_mut, stream::project_all};
+/// use pollster::block_on;
+///
+/// let doubled = project_all(stream::iter(1..=3).fuse(), from_blocking_mut(|x: u32| x * 2));
+///
+/// assert_eq!(block_on(doubled.collect::<Vec<_>>()), [2, 4, 6]);
+/// ```
+#[must_use]
+pub fn project_all<Input, P, B>(input: Input, projection: P) -> ProjectBuffered<Input, P, B, 1>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	ProjectBuffered::new(input, [projection])
+}
+
+/// Projects the items of `input` through the `projections` pool, yielding outputs in input order.
+///
+/// # Panics
+///
+/// Iff `N` is 0.
+#[must_use]
+pub fn project_buffered<Input, P, B, const N: usize>(
+	input: Input,
+	projections: [P; N],
+) -> ProjectBuffered<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	ProjectBuffered::new(input, projections)
+}
+
+/// Projects the items of `input` through the `projections` pool, yielding outputs in completion order.
+///
+/// # Panics
+///
+/// Iff `N` is 0.
+#[must_use]
+pub fn project_buffer_unordered<Input, P, B, const N: usize>(
+	input: Input,
+	projections: [P; N],
+) -> ProjectBufferUnordered<Input, P, B, N>
+where
+	Input: FusedStream,
+	P: ProjectionMut<Input::Item, B>,
+{
+	ProjectBufferUnordered::new(input, projections)
+}
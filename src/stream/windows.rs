@@ -0,0 +1,142 @@
+use super::PartialChunk;
+use core::{
+	convert::TryFrom,
+	future::Future,
+	mem,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use futures_core::{ready, FusedStream};
+use pin_project::pin_project;
+
+/// A lending adapter over overlapping windows of `N` consecutive items of a [`FusedStream`].
+///
+/// Each window is lent out by reference, so this isn't a [`Stream`](`futures_core::Stream`) itself.
+/// Use [`.poll_window(cx)`](`Windows::poll_window`) or [`.next_window()`](`Windows::next_window`) instead.
+///
+/// No windows are produced if the input has fewer than `N` items.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use futures_util::stream::{self, StreamExt as _};
+/// use percolate::stream::windows;
+/// use pollster::block_on;
+///
+/// #[ergo_pin]
+/// async fn sums(input: &[i32]) -> Vec<i32> {
+///     let mut windows = pin!(windows::<_, 3>(stream::iter(input.iter().copied()).fuse()));
+///     let mut sums = vec![];
+///     while let Some(window) = windows.as_mut().next_window().await {
+///         sums.push(window.iter().sum());
+///     }
+///     sums
+/// }
+///
+/// assert_eq!(block_on(sums(&[1, 2, 3, 4, 5])), [6, 9, 12]);
+/// assert_eq!(block_on(sums(&[1, 2])), []);
+/// ```
+#[pin_project]
+pub struct Windows<Input: FusedStream, const N: usize> {
+	#[pin]
+	input: Input,
+	buffer: PartialChunk<Input::Item, N>,
+	/// Whether the current window was already lent out.
+	stale: bool,
+}
+
+impl<Input: FusedStream, const N: usize> Windows<Input, N> {
+	/// # Panics
+	///
+	/// Iff `N` is 0.
+	#[must_use]
+	pub fn new(input: Input) -> Self {
+		assert!(N > 0, "`N` must be greater than 0");
+		Self {
+			input,
+			buffer: PartialChunk::new(),
+			stale: false,
+		}
+	}
+
+	/// Attempts to advance to and lend out the next window.
+	///
+	/// Returns [`None`] once `input` is exhausted.
+	pub fn poll_window(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+	) -> Poll<Option<&[Input::Item; N]>> {
+		if ready!(self.as_mut().poll_fill(cx)) {
+			Poll::Ready(Some(self.lend()))
+		} else {
+			Poll::Ready(None)
+		}
+	}
+
+	/// Advances to and lends out the next window.
+	///
+	/// Returns [`None`] once `input` is exhausted.
+	pub fn next_window(self: Pin<&mut Self>) -> NextWindow<'_, Input, N> {
+		NextWindow(Some(self))
+	}
+
+	/// Buffers items until a fresh window is available or `input` ends.
+	fn poll_fill(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+		let mut this = self.project();
+		while this.buffer.len() < N || *this.stale {
+			if this.input.is_terminated() {
+				return Poll::Ready(false);
+			}
+			let item = match ready!(this.input.as_mut().poll_next(cx)) {
+				Some(item) => item,
+				None => return Poll::Ready(false),
+			};
+			if let Err(item) = this.buffer.push(item) {
+				this.buffer.rotate_left(1);
+				drop(mem::replace(&mut this.buffer[N - 1], item));
+				*this.stale = false;
+			}
+		}
+		Poll::Ready(true)
+	}
+
+	fn lend(self: Pin<&mut Self>) -> &[Input::Item; N] {
+		let this = self.project();
+		*this.stale = true;
+		<&[Input::Item; N]>::try_from(&**this.buffer).expect("unreachable")
+	}
+}
+
+/// The [`Future`] returned by [`Windows::next_window`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NextWindow<'a, Input: FusedStream, const N: usize>(
+	Option<Pin<&'a mut Windows<Input, N>>>,
+);
+
+impl<'a, Input: FusedStream, const N: usize> Future for NextWindow<'a, Input, N> {
+	type Output = Option<&'a [Input::Item; N]>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let windows = self
+			.0
+			.as_mut()
+			.expect("`NextWindow::poll` called after completion");
+		if ready!(windows.as_mut().poll_fill(cx)) {
+			Poll::Ready(Some(self.0.take().expect("unreachable").lend()))
+		} else {
+			self.0 = None;
+			Poll::Ready(None)
+		}
+	}
+}
+
+/// [`FusedStream`] → [`Windows`]
+///
+/// # Panics
+///
+/// Iff `N` is 0.
+#[must_use]
+pub fn windows<Input: FusedStream, const N: usize>(input: Input) -> Windows<Input, N> {
+	Windows::new(input)
+}
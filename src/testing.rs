@@ -0,0 +1,684 @@
+//! Executor-agnostic test utilities. Requires the `testing` feature.
+//!
+//! * [`block_on`] is a deterministic single-threaded executor that panics on lost wake-ups.
+//! * [`WakeCounter`] is a [`Waker`] source that counts wake-ups.
+//! * [`ScriptedStream`] yields items or [`Poll::Pending`] according to a script of [`Step`]s.
+//! * [`ScriptedFuture`] returns [`Poll::Pending`] a given number of times before completing.
+//! * [`ScriptedProjection`] is a [`FusedRefProjectionMut`](`crate::projection::FusedRefProjectionMut`) over [`ScriptedFuture`]s.
+//! * [`DropTracker`] counts live [`Tracked`] values, to find leaks and double drops.
+//! * [`assert_pending!`](`crate::assert_pending`) and [`assert_ready!`](`crate::assert_ready`)
+//!   poll a [`Future`] + [`Unpin`] (like a [`PinHandleMut`](`crate::handles::PinHandleMut`)) once.
+//!
+//! # Example
+//!
+//! ```
+//! use core::{num::NonZeroUsize, task::Context};
+//! use percolate::{
+//!     assert_pending, assert_ready,
+//!     stream::PeekStream,
+//!     testing::{ScriptedStream, Step, WakeCounter},
+//! };
+//!
+//! let mut stream = Box::pin(PeekStream::<_, 2>::new(ScriptedStream::new(vec![
+//!     Step::Pending,
+//!     Step::Ready('a'),
+//! ])));
+//! let counter = WakeCounter::new();
+//! let waker = counter.waker();
+//! let mut cx = Context::from_waker(&waker);
+//!
+//! let mut peek = Box::pin(stream.as_mut().peek_1());
+//! assert_pending!(peek, &mut cx);
+//! assert_eq!(counter.count(), 1);
+//! assert_eq!(assert_ready!(peek, &mut cx), Some(&'a'));
+//! ```
+
+use crate::projection::{
+	FusedPollProjectionMut, IntoFusedMutProjectionMut, IntoFusedProjectionMut,
+	IntoFusedRefProjectionMut, IntoMutProjectionMut, IntoProjectionMut, IntoRefProjectionMut,
+	PollProjectionMut,
+};
+use alloc::{collections::VecDeque, sync::Arc};
+use core::{
+	fmt::{self, Debug, Formatter},
+	future::Future,
+	iter,
+	marker::PhantomData,
+	mem::ManuallyDrop,
+	ops::{Deref, DerefMut},
+	pin::Pin,
+	ptr,
+	sync::atomic::{AtomicUsize, Ordering},
+	task::{Context, Poll, Waker},
+};
+use futures_core::{FusedFuture, FusedStream, Stream};
+use futures_util::{
+	pin_mut,
+	task::{self, ArcWake},
+};
+
+/// Runs `future` to completion on the current thread.
+///
+/// # Panics
+///
+/// Iff `future` returns [`Poll::Pending`] without arranging for its [`Waker`] to be woken,
+/// in which case a real single-threaded executor would hang forever.
+///
+/// Wake-ups are only observed if they happen during the poll, so this doesn't work with futures that rely on other threads.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::stream::StreamExt as _;
+/// use percolate::testing::{block_on, ScriptedStream};
+///
+/// let items: Vec<_> = block_on(ScriptedStream::pending_before_each(1..=3).collect());
+/// assert_eq!(items, [1, 2, 3]);
+/// ```
+pub fn block_on<F: Future>(future: F) -> F::Output {
+	pin_mut!(future);
+	let counter = WakeCounter::new();
+	let waker = counter.waker();
+	let mut cx = Context::from_waker(&waker);
+	loop {
+		let before = counter.count();
+		match future.as_mut().poll(&mut cx) {
+			Poll::Ready(output) => return output,
+			Poll::Pending => assert!(
+				counter.count() > before,
+				"lost wake-up: future returned `Pending` without waking its `Waker`"
+			),
+		}
+	}
+}
+
+/// Polls `future` once.
+pub fn poll_once<F: ?Sized + Future + Unpin>(
+	future: &mut F,
+	cx: &mut Context<'_>,
+) -> Poll<F::Output> {
+	Pin::new(future).poll(cx)
+}
+
+/// Creates [`Waker`]s that count how often they (or their clones) were woken.
+#[derive(Debug, Clone, Default)]
+pub struct WakeCounter(Arc<Count>);
+
+#[derive(Debug, Default)]
+struct Count(AtomicUsize);
+
+impl ArcWake for Count {
+	fn wake_by_ref(arc_self: &Arc<Self>) {
+		arc_self.0.fetch_add(1, Ordering::SeqCst);
+	}
+}
+
+impl WakeCounter {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// A new [`Waker`] that increments this counter.
+	#[must_use]
+	pub fn waker(&self) -> Waker {
+		task::waker(self.0.clone())
+	}
+
+	/// How often any [`Waker`] of this counter was woken so far.
+	#[must_use]
+	pub fn count(&self) -> usize {
+		self.0 .0.load(Ordering::SeqCst)
+	}
+
+	/// How many [`Waker`]s of this counter currently exist.
+	#[must_use]
+	pub fn wakers(&self) -> usize {
+		Arc::strong_count(&self.0) - 1
+	}
+}
+
+/// A step of a [`ScriptedStream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step<T> {
+	/// Yield this item.
+	Ready(T),
+	/// Return [`Poll::Pending`] once, waking the current task immediately.
+	Pending,
+}
+
+/// A [`FusedStream`] that follows a script of [`Step`]s and then ends.
+///
+/// # Panics
+///
+/// When polled again after it ended, since adapters shouldn't need to do so.
+pub struct ScriptedStream<T> {
+	script: VecDeque<Step<T>>,
+	ended: bool,
+	polls: usize,
+}
+
+impl<T> ScriptedStream<T> {
+	#[must_use]
+	pub fn new(script: impl IntoIterator<Item = Step<T>>) -> Self {
+		Self {
+			script: script.into_iter().collect(),
+			ended: false,
+			polls: 0,
+		}
+	}
+
+	/// Creates a [`ScriptedStream`] that returns [`Poll::Pending`] once before each of `items`, and before ending.
+	#[must_use]
+	pub fn pending_before_each(items: impl IntoIterator<Item = T>) -> Self {
+		let mut script: VecDeque<_> = items
+			.into_iter()
+			.flat_map(|item| iter::once(Step::Pending).chain(iter::once(Step::Ready(item))))
+			.collect();
+		script.push_back(Step::Pending);
+		Self {
+			script,
+			ended: false,
+			polls: 0,
+		}
+	}
+
+	/// How often `self` was polled so far.
+	#[must_use]
+	pub fn polls(&self) -> usize {
+		self.polls
+	}
+
+	/// The steps that haven't been reached yet.
+	#[must_use]
+	pub fn remaining(&self) -> &VecDeque<Step<T>> {
+		&self.script
+	}
+}
+
+/// Items are never pinned.
+impl<T> Unpin for ScriptedStream<T> {}
+
+impl<T> Stream for ScriptedStream<T> {
+	type Item = T;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		assert!(!self.ended, "`ScriptedStream` polled after it ended");
+		self.polls += 1;
+		match self.script.pop_front() {
+			Some(Step::Ready(item)) => Poll::Ready(Some(item)),
+			Some(Step::Pending) => {
+				cx.waker().wake_by_ref();
+				Poll::Pending
+			}
+			None => {
+				self.ended = true;
+				Poll::Ready(None)
+			}
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self
+			.script
+			.iter()
+			.filter(|step| matches!(step, Step::Ready(_)))
+			.count();
+		(len, Some(len))
+	}
+}
+
+impl<T> FusedStream for ScriptedStream<T> {
+	fn is_terminated(&self) -> bool {
+		self.ended
+	}
+}
+
+impl<T: Debug> Debug for ScriptedStream<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ScriptedStream")
+			.field("script", &self.script)
+			.field("ended", &self.ended)
+			.field("polls", &self.polls)
+			.finish()
+	}
+}
+
+/// A [`FusedFuture`] that returns [`Poll::Pending`] a given number of times, waking the current task immediately each time, and then completes.
+///
+/// # Panics
+///
+/// When polled again after it completed.
+///
+/// # Example
+///
+/// ```
+/// use percolate::testing::{block_on, ScriptedFuture};
+///
+/// assert_eq!(block_on(ScriptedFuture::new(3, "done")), "done");
+/// ```
+#[derive(Debug)]
+pub struct ScriptedFuture<T> {
+	pending: usize,
+	output: Option<T>,
+	polls: usize,
+}
+
+impl<T> ScriptedFuture<T> {
+	#[must_use]
+	pub fn new(pending: usize, output: T) -> Self {
+		Self {
+			pending,
+			output: Some(output),
+			polls: 0,
+		}
+	}
+
+	/// How often `self` was polled so far.
+	#[must_use]
+	pub fn polls(&self) -> usize {
+		self.polls
+	}
+}
+
+/// The output is never pinned.
+impl<T> Unpin for ScriptedFuture<T> {}
+
+impl<T> Future for ScriptedFuture<T> {
+	type Output = T;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		self.polls += 1;
+		if self.pending > 0 {
+			self.pending -= 1;
+			cx.waker().wake_by_ref();
+			Poll::Pending
+		} else {
+			Poll::Ready(
+				self.output
+					.take()
+					.expect("`ScriptedFuture` polled after it completed"),
+			)
+		}
+	}
+}
+
+impl<T> FusedFuture for ScriptedFuture<T> {
+	fn is_terminated(&self) -> bool {
+		self.output.is_none()
+	}
+}
+
+/// A [`FusedRefProjectionMut<A, B>`](`crate::projection::FusedRefProjectionMut`) that calls `projection` immediately,
+/// but returns its result through a [`ScriptedFuture`] that is pending `pending` times.
+///
+/// # Example
+///
+/// ```
+/// use ergo_pin::ergo_pin;
+/// use percolate::{
+///     projection::{branch, RefProjectionMut},
+///     testing::{block_on, ScriptedProjection},
+/// };
+///
+/// #[ergo_pin]
+/// async fn abs(value: i32) -> i32 {
+///     pin!(branch(
+///         ScriptedProjection::new(1, |x: &i32| *x < 0),
+///         ScriptedProjection::new(2, |x: &i32| -x),
+///         ScriptedProjection::new(3, |x: &i32| *x),
+///     ))
+///     .project_ref(&value)
+///     .await
+/// }
+///
+/// assert_eq!(block_on(abs(-1)), 1);
+/// assert_eq!(block_on(abs(1)), 1);
+/// ```
+pub struct ScriptedProjection<P, A: ?Sized, B>
+where
+	P: FnMut(&A) -> B,
+{
+	projection: P,
+	pending: usize,
+	future: Option<ScriptedFuture<B>>,
+	projections: usize,
+	_phantom: PhantomData<fn(&A)>,
+}
+
+impl<P, A: ?Sized, B> ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	#[must_use]
+	pub fn new(pending: usize, projection: P) -> Self {
+		Self {
+			projection,
+			pending,
+			future: None,
+			projections: 0,
+			_phantom: PhantomData,
+		}
+	}
+
+	/// How often `self` was projected through so far.
+	#[must_use]
+	pub fn projections(&self) -> usize {
+		self.projections
+	}
+}
+
+/// Neither `P` nor the futures are ever pinned structurally.
+impl<P, A: ?Sized, B> Unpin for ScriptedProjection<P, A, B> where P: FnMut(&A) -> B {}
+
+impl<P, A: ?Sized, B> IntoRefProjectionMut<A, B, Self> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	type IntoRefProjMut = Self;
+	fn into_ref_projection_mut(self) -> Self::IntoRefProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoMutProjectionMut<A, B, Self> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	type IntoMutProjMut = Self;
+	fn into_mut_projection_mut(self) -> Self::IntoMutProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoFusedRefProjectionMut<A, B, Self> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	type IntoFusedRefProjMut = Self;
+	fn into_fused_ref_projection_mut(self) -> Self::IntoFusedRefProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoFusedMutProjectionMut<A, B, Self> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	type IntoFusedMutProjMut = Self;
+	fn into_fused_mut_projection_mut(self) -> Self::IntoFusedMutProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoProjectionMut<&A, B, Self> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoProjectionMut<&mut A, B, Self> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	type IntoProjMut = Self;
+	fn into_projection_mut(self) -> Self::IntoProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoFusedProjectionMut<&A, B, Self> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> IntoFusedProjectionMut<&mut A, B, Self> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	type IntoFusedProjMut = Self;
+	fn into_fused_projection_mut(self) -> Self::IntoFusedProjMut {
+		self
+	}
+}
+
+impl<P, A: ?Sized, B> PollProjectionMut<&A, B> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &A) {
+		let this = Pin::into_inner(self);
+		this.projections += 1;
+		let output = (this.projection)(value);
+		this.future = Some(ScriptedFuture::new(this.pending, output));
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		let future = Pin::into_inner(self)
+			.future
+			.as_mut()
+			.expect("`ScriptedProjection` polled without a projection in progress");
+		Pin::new(future).poll(cx)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		Pin::into_inner(self).future = None;
+	}
+}
+
+impl<P, A: ?Sized, B> PollProjectionMut<&mut A, B> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	unsafe fn start(self: Pin<&mut Self>, value: &mut A) {
+		PollProjectionMut::<&A, B>::start(self, value);
+	}
+
+	fn poll_result(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+		PollProjectionMut::<&A, B>::poll_result(self, cx)
+	}
+
+	fn clear(self: Pin<&mut Self>) {
+		PollProjectionMut::<&A, B>::clear(self);
+	}
+}
+
+impl<P, A: ?Sized, B> FusedPollProjectionMut<&A, B> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	fn is_terminated(&self) -> bool {
+		self.future
+			.as_ref()
+			.map_or(true, FusedFuture::is_terminated)
+	}
+}
+
+impl<P, A: ?Sized, B> FusedPollProjectionMut<&mut A, B> for ScriptedProjection<P, A, B>
+where
+	P: FnMut(&A) -> B,
+{
+	fn is_terminated(&self) -> bool {
+		FusedPollProjectionMut::<&A, B>::is_terminated(self)
+	}
+}
+
+/// Counts [`Tracked`] values that are created and dropped.
+///
+/// # Example
+///
+/// ```
+/// use percolate::testing::DropTracker;
+///
+/// let tracker = DropTracker::new();
+/// let items = vec![tracker.track(1), tracker.track(2)];
+/// assert_eq!(tracker.alive(), 2);
+/// drop(items);
+/// assert_eq!(tracker.alive(), 0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DropTracker(Arc<Counters>);
+
+#[derive(Debug, Default)]
+struct Counters {
+	created: AtomicUsize,
+	dropped: AtomicUsize,
+}
+
+impl DropTracker {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Wraps `value` so that its drop is counted.
+	pub fn track<T>(&self, value: T) -> Tracked<T> {
+		self.0.created.fetch_add(1, Ordering::SeqCst);
+		Tracked {
+			value: ManuallyDrop::new(value),
+			tracker: self.clone(),
+		}
+	}
+
+	/// How many [`Tracked`] values were created so far.
+	#[must_use]
+	pub fn created(&self) -> usize {
+		self.0.created.load(Ordering::SeqCst)
+	}
+
+	/// How many [`Tracked`] values were dropped (or unwrapped) so far.
+	#[must_use]
+	pub fn dropped(&self) -> usize {
+		self.0.dropped.load(Ordering::SeqCst)
+	}
+
+	/// How many [`Tracked`] values currently exist.
+	///
+	/// # Panics
+	///
+	/// Iff more values were dropped than created, which means a value was dropped twice.
+	#[must_use]
+	pub fn alive(&self) -> usize {
+		self.created()
+			.checked_sub(self.dropped())
+			.expect("more `Tracked` values dropped than created")
+	}
+}
+
+/// A value whose drop is counted by a [`DropTracker`].
+///
+/// Dereferences to the value.
+pub struct Tracked<T> {
+	value: ManuallyDrop<T>,
+	tracker: DropTracker,
+}
+
+impl<T> Tracked<T> {
+	/// Unwraps the value, which counts as dropping `this`.
+	pub fn into_inner(this: Self) -> T {
+		let mut this = ManuallyDrop::new(this);
+		this.tracker.0.dropped.fetch_add(1, Ordering::SeqCst);
+		unsafe {
+			// Safety: `this` is never used again, and its tracker is dropped separately below.
+			let value = ManuallyDrop::take(&mut this.value);
+			drop(ptr::read(&this.tracker));
+			value
+		}
+	}
+}
+
+impl<T> Drop for Tracked<T> {
+	fn drop(&mut self) {
+		self.tracker.0.dropped.fetch_add(1, Ordering::SeqCst);
+		unsafe {
+			// Safety: Only dropped here.
+			ManuallyDrop::drop(&mut self.value);
+		}
+	}
+}
+
+impl<T> Deref for Tracked<T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.value
+	}
+}
+
+impl<T> DerefMut for Tracked<T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.value
+	}
+}
+
+/// Clones are tracked as separate values.
+impl<T: Clone> Clone for Tracked<T> {
+	fn clone(&self) -> Self {
+		self.tracker.track((**self).clone())
+	}
+}
+
+impl<T: Debug> Debug for Tracked<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		(**self).fmt(f)
+	}
+}
+
+impl<T: PartialEq<U>, U: ?Sized> PartialEq<U> for Tracked<T> {
+	fn eq(&self, other: &U) -> bool {
+		**self == *other
+	}
+}
+
+/// Polls an [`Unpin`] [`Future`] once and asserts that it is [`Pending`](`core::task::Poll::Pending`).
+///
+/// The optional second argument is a `&mut Context`. Otherwise the [`Waker`](`core::task::Waker`) of a new [`WakeCounter`](`crate::testing::WakeCounter`) is used.
+///
+/// Requires the `testing` feature.
+#[macro_export]
+macro_rules! assert_pending {
+	($future:expr $(,)?) => {{
+		let waker = $crate::testing::WakeCounter::new().waker();
+		$crate::assert_pending!($future, &mut ::core::task::Context::from_waker(&waker));
+	}};
+	($future:expr, $cx:expr $(,)?) => {
+		match $crate::testing::poll_once(&mut $future, $cx) {
+			::core::task::Poll::Pending => (),
+			::core::task::Poll::Ready(_) => panic!(
+				"assertion failed: `{}` is pending, but it is ready",
+				stringify!($future)
+			),
+		}
+	};
+}
+
+/// Polls an [`Unpin`] [`Future`] once, asserts that it is [`Ready`](`core::task::Poll::Ready`) and evaluates to its output.
+///
+/// The optional second argument is a `&mut Context`. Otherwise the [`Waker`](`core::task::Waker`) of a new [`WakeCounter`](`crate::testing::WakeCounter`) is used.
+///
+/// Requires the `testing` feature.
+#[macro_export]
+macro_rules! assert_ready {
+	($future:expr $(,)?) => {{
+		let waker = $crate::testing::WakeCounter::new().waker();
+		$crate::assert_ready!($future, &mut ::core::task::Context::from_waker(&waker))
+	}};
+	($future:expr, $cx:expr $(,)?) => {
+		match $crate::testing::poll_once(&mut $future, $cx) {
+			::core::task::Poll::Ready(output) => output,
+			::core::task::Poll::Pending => panic!(
+				"assertion failed: `{}` is ready, but it is pending",
+				stringify!($future)
+			),
+		}
+	};
+}
@@ -0,0 +1,230 @@
+//! Executor-agnostic timing.
+//!
+//! This crate doesn't come with a clock of its own.
+//! Instead, adapters that need to wait for some time are generic over a [`Timer`],
+//! which can be implemented for whichever runtime (or hardware timer) is available.
+//!
+//! [`ManualClock`] is a deterministic [`Timer`] that only advances when told to,
+//! which is useful for tests.
+
+use core::{
+	cell::{Cell, RefCell},
+	fmt::{self, Display, Formatter},
+	mem::{self, MaybeUninit},
+	pin::Pin,
+	task::{Context, Poll, Waker},
+	time::Duration,
+};
+use futures_core::{FusedFuture, Future};
+
+/// Creates [`Future`]s that complete after a given duration.
+pub trait Timer {
+	type Duration: Clone;
+	type Delay: Future<Output = ()>;
+
+	/// Starts a delay of `duration`, measured from now.
+	fn delay(&mut self, duration: Self::Duration) -> Self::Delay;
+}
+
+impl<T: ?Sized + Timer> Timer for &mut T {
+	type Duration = T::Duration;
+	type Delay = T::Delay;
+
+	fn delay(&mut self, duration: Self::Duration) -> Self::Delay {
+		(**self).delay(duration)
+	}
+}
+
+/// The error returned when a time limit was exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Elapsed;
+
+impl Display for Elapsed {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("time limit elapsed")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Elapsed {}
+
+/// A [`Timer`] (by reference) that only advances through [`.advance(…)`](`ManualClock::advance`).
+///
+/// Up to `WAKERS` distinct [`Waker`]s are stored while their tasks wait on a [`ManualDelay`].
+/// If more than that are registered, the oldest ones are woken early and will register again when polled.
+///
+/// # Example
+///
+/// ```
+/// use core::{future::Future, pin::Pin, task::{Context, Poll}, time::Duration};
+/// use futures_util::task::noop_waker;
+/// use percolate::time::{ManualClock, Timer};
+///
+/// let clock = ManualClock::<1>::new();
+/// let mut delay = (&clock).delay(Duration::from_secs(2));
+/// let waker = noop_waker();
+/// let mut cx = Context::from_waker(&waker);
+///
+/// clock.advance(Duration::from_secs(1));
+/// assert_eq!(Pin::new(&mut delay).poll(&mut cx), Poll::Pending);
+/// clock.advance(Duration::from_secs(1));
+/// assert_eq!(Pin::new(&mut delay).poll(&mut cx), Poll::Ready(()));
+/// ```
+pub struct ManualClock<const WAKERS: usize> {
+	now: Cell<Duration>,
+	wakers: RefCell<WakerList<WAKERS>>,
+}
+
+impl<const WAKERS: usize> ManualClock<WAKERS> {
+	/// Creates a new [`ManualClock`] at zero.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			now: Cell::new(Duration::from_secs(0)),
+			wakers: RefCell::new(WakerList::new()),
+		}
+	}
+
+	/// The time that has passed on this clock since its creation.
+	#[must_use]
+	pub fn now(&self) -> Duration {
+		self.now.get()
+	}
+
+	/// Advances the time on this clock by `duration` and wakes all waiting tasks.
+	///
+	/// # Panics
+	///
+	/// Iff the time overflows.
+	pub fn advance(&self, duration: Duration) {
+		self.now.set(
+			self.now
+				.get()
+				.checked_add(duration)
+				.expect("`ManualClock` overflow in `advance`"),
+		);
+		loop {
+			// Released before waking, in case that's reentrant.
+			let waker = self.wakers.borrow_mut().pop();
+			match waker {
+				Some(waker) => waker.wake(),
+				None => break,
+			}
+		}
+	}
+
+	fn register(&self, waker: &Waker) {
+		let evicted = self.wakers.borrow_mut().insert(waker);
+		if let Some(evicted) = evicted {
+			evicted.wake();
+		}
+	}
+}
+
+impl<const WAKERS: usize> Default for ManualClock<WAKERS> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<'a, const WAKERS: usize> Timer for &'a ManualClock<WAKERS> {
+	type Duration = Duration;
+	type Delay = ManualDelay<'a, WAKERS>;
+
+	fn delay(&mut self, duration: Self::Duration) -> Self::Delay {
+		ManualDelay {
+			clock: self,
+			deadline: Some(
+				self.now()
+					.checked_add(duration)
+					.expect("`ManualClock` overflow in `delay`"),
+			),
+		}
+	}
+}
+
+/// The [`Timer::Delay`] of [`&ManualClock`](`ManualClock`).
+pub struct ManualDelay<'a, const WAKERS: usize> {
+	clock: &'a ManualClock<WAKERS>,
+	/// [`None`] once completed.
+	deadline: Option<Duration>,
+}
+
+impl<const WAKERS: usize> Future for ManualDelay<'_, WAKERS> {
+	type Output = ();
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let deadline = self
+			.deadline
+			.expect("`ManualDelay::poll` called after completion");
+		if self.clock.now() >= deadline {
+			self.deadline = None;
+			Poll::Ready(())
+		} else {
+			self.clock.register(cx.waker());
+			Poll::Pending
+		}
+	}
+}
+
+impl<const WAKERS: usize> FusedFuture for ManualDelay<'_, WAKERS> {
+	fn is_terminated(&self) -> bool {
+		self.deadline.is_none()
+	}
+}
+
+/// Oldest first.
+struct WakerList<const CAPACITY: usize> {
+	wakers: [MaybeUninit<Waker>; CAPACITY],
+	len: usize,
+}
+
+impl<const CAPACITY: usize> WakerList<CAPACITY> {
+	fn new() -> Self {
+		Self {
+			wakers: unsafe {
+				// Safety: An array of `MaybeUninit` doesn't need initialisation.
+				MaybeUninit::<[MaybeUninit<Waker>; CAPACITY]>::uninit().assume_init()
+			},
+			len: 0,
+		}
+	}
+
+	/// Returns a [`Waker`] that must be woken to make room, if necessary.
+	fn insert(&mut self, waker: &Waker) -> Option<Waker> {
+		if self.wakers[..self.len]
+			.iter()
+			.any(|stored| waker.will_wake(unsafe { &*stored.as_ptr() }))
+		{
+			return None;
+		}
+		if CAPACITY == 0 {
+			return Some(waker.clone());
+		}
+		let evicted = (self.len == CAPACITY).then(|| {
+			let evicted = unsafe { self.wakers[0].as_ptr().read() };
+			self.wakers.rotate_left(1);
+			self.len -= 1;
+			evicted
+		});
+		self.wakers[self.len] = MaybeUninit::new(waker.clone());
+		self.len += 1;
+		evicted
+	}
+
+	fn pop(&mut self) -> Option<Waker> {
+		(self.len > 0).then(|| {
+			self.len -= 1;
+			unsafe { self.wakers[self.len].as_ptr().read() }
+		})
+	}
+}
+
+impl<const CAPACITY: usize> Drop for WakerList<CAPACITY> {
+	fn drop(&mut self) {
+		let len = mem::replace(&mut self.len, 0);
+		for waker in &mut self.wakers[..len] {
+			unsafe { waker.as_mut_ptr().drop_in_place() }
+		}
+	}
+}
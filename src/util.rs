@@ -0,0 +1,16 @@
+use core::{mem::MaybeUninit, ptr};
+
+/// Like `core::array::from_fn`, which isn't available at this crate's MSRV.
+pub(crate) fn array_from_fn<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> [T; N] {
+	let mut array: [MaybeUninit<T>; N] = unsafe {
+		// Safety: An array of `MaybeUninit` doesn't need initialisation.
+		MaybeUninit::uninit().assume_init()
+	};
+	for (i, element) in array.iter_mut().enumerate() {
+		*element = MaybeUninit::new(f(i));
+	}
+	unsafe {
+		// Safety: Fully initialised above. (`transmute` doesn't work with const generic array sizes yet.)
+		ptr::read(array.as_ptr().cast::<[T; N]>())
+	}
+}
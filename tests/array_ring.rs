@@ -0,0 +1,117 @@
+use percolate::ring::{ArrayRing, RingIndex};
+use std::rc::Rc;
+
+#[test]
+fn push_front_wraps_below_zero() {
+	let mut ring = ArrayRing::<_, 4>::new();
+	assert_eq!(ring.push_front(2), Ok(()));
+	assert_eq!(ring.push_front(1), Ok(()));
+	assert_eq!(ring.push_back(3), Ok(()));
+	assert_eq!(ring.as_slices(), (&[1, 2][..], &[3][..]));
+	assert!(ring.iter().copied().eq(1..=3));
+	assert!(ring.iter().rev().copied().eq((1..=3).rev()));
+	assert_eq!((ring.front(), ring.back()), (Some(&1), Some(&3)));
+
+	assert_eq!(ring.push_front(0), Ok(()));
+	assert_eq!(ring.push_front(-1), Err(-1));
+	assert_eq!(ring.as_slices(), (&[0, 1, 2][..], &[3][..]));
+}
+
+#[test]
+fn pop_back_crosses_the_wrap() {
+	let mut ring = ArrayRing::<_, 3>::new();
+	for i in 1..=3 {
+		ring.push_front(i).unwrap();
+	}
+	assert_eq!(ring.pop_back(), Some(1));
+	assert_eq!(ring.pop_back(), Some(2));
+	ring.push_back(4).unwrap();
+	ring.push_back(5).unwrap();
+	assert!(ring.iter().copied().eq([3, 4, 5].iter().copied()));
+	assert_eq!(ring.pop_back(), Some(5));
+	assert_eq!(ring.pop_back(), Some(4));
+	assert_eq!(ring.pop_back(), Some(3));
+	assert_eq!(ring.pop_back(), None);
+	assert!(ring.is_empty());
+}
+
+#[test]
+fn ring_index_wraps_both_ways() {
+	let zero = RingIndex::<3>::new(0).unwrap();
+	assert_eq!((zero - 1).get(), 2);
+	assert_eq!((zero + 4).get(), 1);
+	assert_eq!(zero - (zero - 1), 1);
+	assert_eq!((zero - 1) - zero, 2);
+	assert_eq!((RingIndex::<1>::new(0).unwrap() - usize::MAX).get(), 0);
+	assert!(RingIndex::<0>::new(0).is_none());
+}
+
+#[test]
+fn drops_wrapped_items_once() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	let item = |i| (i, Rc::clone(&value));
+	{
+		let mut ring = ArrayRing::<_, 4>::new();
+		for i in 0..3 {
+			ring.push_back(item(i)).unwrap();
+		}
+		assert_eq!(ring.pop_front().unwrap().0, 0);
+		assert_eq!(ring.pop_front().unwrap().0, 1);
+		for i in 3..6 {
+			ring.push_back(item(i)).unwrap();
+		}
+		assert_eq!(ring.as_slices().1.len(), 2);
+		assert_eq!(alive(), 4);
+
+		// `Err` hands the item back rather than dropping it.
+		let rejected = ring.push_front(item(6)).unwrap_err();
+		assert_eq!(alive(), 5);
+		drop(rejected);
+		assert_eq!(ring.pop_back().unwrap().0, 5);
+		assert_eq!(alive(), 3);
+	}
+	assert_eq!(alive(), 0);
+}
+
+#[test]
+fn clear_drops_both_slices() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	let mut ring = ArrayRing::<_, 3>::new();
+	ring.push_back((1, Rc::clone(&value))).unwrap();
+	ring.push_front((0, Rc::clone(&value))).unwrap();
+	ring.push_back((2, Rc::clone(&value))).unwrap();
+	assert_eq!(ring.as_slices().0.len(), 1);
+
+	ring.clear();
+	assert!(ring.is_empty());
+	assert_eq!(alive(), 0);
+
+	ring.push_front((3, Rc::clone(&value))).unwrap();
+	assert_eq!(alive(), 1);
+	drop(ring);
+	assert_eq!(alive(), 0);
+}
+
+#[test]
+fn into_iter_drops_the_rest() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	let mut ring = ArrayRing::<_, 3>::new();
+	for i in 0..3 {
+		ring.push_front((i, Rc::clone(&value))).unwrap();
+	}
+	let clone = ring.clone();
+	assert_eq!(alive(), 6);
+	assert_eq!(clone, ring);
+	drop(clone);
+	assert_eq!(alive(), 3);
+
+	let mut iter = ring.into_iter();
+	assert_eq!(iter.next().unwrap().0, 2);
+	assert_eq!(iter.next_back().unwrap().0, 0);
+	assert_eq!(alive(), 1);
+	drop(iter);
+	assert_eq!(alive(), 0);
+}
@@ -0,0 +1,155 @@
+use core::{
+	sync::atomic::{AtomicUsize, Ordering},
+	task::{Context, Poll, Waker},
+};
+use ergo_pin::ergo_pin;
+use futures_util::stream::{self, StreamExt as _};
+use percolate::stream::{Broadcast, VecBroadcast};
+use std::{
+	cell::{Cell, RefCell},
+	rc::Rc,
+	sync::Arc,
+	task::Wake,
+};
+
+/// Counts how often its [`Waker`]s were woken.
+#[derive(Default)]
+struct WakeCount(AtomicUsize);
+
+impl WakeCount {
+	fn get(&self) -> usize {
+		self.0.load(Ordering::SeqCst)
+	}
+}
+
+impl Wake for WakeCount {
+	fn wake(self: Arc<Self>) {
+		self.wake_by_ref();
+	}
+
+	fn wake_by_ref(self: &Arc<Self>) {
+		self.0.fetch_add(1, Ordering::SeqCst);
+	}
+}
+
+#[test]
+#[ergo_pin]
+fn dropping_the_slowest_cursor_releases_backpressure() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	let input = stream::iter((1..=3).map(|i| (i, Rc::clone(&value)))).fuse();
+	let broadcast = pin!(Broadcast::<_, 2, 2>::new(input));
+	let mut fast = broadcast.as_ref().cursor().unwrap();
+	let slow = broadcast.as_ref().cursor().unwrap();
+
+	let count = Arc::new(WakeCount::default());
+	let waker = Waker::from(Arc::clone(&count));
+	let mut cx = Context::from_waker(&waker);
+
+	assert_eq!(
+		fast.poll_next_unpin(&mut cx).map(|item| item.unwrap().0),
+		Poll::Ready(1)
+	);
+	assert_eq!(
+		fast.poll_next_unpin(&mut cx).map(|item| item.unwrap().0),
+		Poll::Ready(2)
+	);
+	assert!(fast.poll_next_unpin(&mut cx).is_pending());
+	assert_eq!(count.get(), 0);
+	assert_eq!(alive(), 2);
+
+	drop(slow);
+	assert_eq!(count.get(), 1);
+	assert_eq!(alive(), 0);
+
+	assert_eq!(
+		fast.poll_next_unpin(&mut cx).map(|item| item.unwrap().0),
+		Poll::Ready(3)
+	);
+	assert!(matches!(fast.poll_next_unpin(&mut cx), Poll::Ready(None)));
+	assert_eq!(alive(), 0);
+}
+
+#[test]
+#[ergo_pin]
+fn only_the_last_waiting_cursor_releases_backpressure() {
+	let broadcast = pin!(VecBroadcast::new(stream::iter(1..=2).fuse(), 1));
+	let mut fast = broadcast.as_ref().cursor();
+	let slow = broadcast.as_ref().cursor();
+	let other = slow.fork().unwrap();
+
+	let count = Arc::new(WakeCount::default());
+	let waker = Waker::from(Arc::clone(&count));
+	let mut cx = Context::from_waker(&waker);
+
+	assert_eq!(fast.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+	assert!(fast.poll_next_unpin(&mut cx).is_pending());
+
+	// `other` still waits for `1`.
+	drop(slow);
+	assert_eq!(count.get(), 0);
+	drop(other);
+	assert_eq!(count.get(), 1);
+
+	assert_eq!(fast.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+}
+
+#[test]
+#[ergo_pin]
+fn buffered_items_are_dropped_with_the_broadcast() {
+	let value = Rc::new(());
+	{
+		let input = stream::iter((1..=3).map(|i| (i, Rc::clone(&value)))).fuse();
+		let broadcast = pin!(Broadcast::<_, 4, 2>::new(input));
+		let mut cursor = broadcast.as_ref().cursor().unwrap();
+		let _idle = broadcast.as_ref().cursor().unwrap();
+
+		let waker = Waker::from(Arc::new(WakeCount::default()));
+		let mut cx = Context::from_waker(&waker);
+		while let Poll::Ready(Some(_)) = cursor.poll_next_unpin(&mut cx) {}
+		assert_eq!(Rc::strong_count(&value), 4);
+	}
+	assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+#[ergo_pin]
+fn dropping_the_cursor_input_would_wake_wakes_the_others() {
+	let open = Cell::new(false);
+	let input_waker = RefCell::new(None::<Waker>);
+	let input = stream::poll_fn(|cx| {
+		if open.get() {
+			Poll::Ready(Some(1))
+		} else {
+			*input_waker.borrow_mut() = Some(cx.waker().clone());
+			Poll::Pending
+		}
+	})
+	.fuse();
+	let broadcast = pin!(Broadcast::<_, 2, 2>::new(input));
+	let mut a = broadcast.as_ref().cursor().unwrap();
+	let mut c = broadcast.as_ref().cursor().unwrap();
+
+	let count_a = Arc::new(WakeCount::default());
+	let waker_a = Waker::from(Arc::clone(&count_a));
+	let count_c = Arc::new(WakeCount::default());
+	let waker_c = Waker::from(Arc::clone(&count_c));
+
+	assert!(a
+		.poll_next_unpin(&mut Context::from_waker(&waker_a))
+		.is_pending());
+	assert!(c
+		.poll_next_unpin(&mut Context::from_waker(&waker_c))
+		.is_pending());
+	assert!(input_waker.borrow().as_ref().unwrap().will_wake(&waker_c));
+
+	drop(c);
+	assert_eq!(count_a.get(), 1);
+
+	open.set(true);
+	input_waker.borrow_mut().take().unwrap().wake();
+	assert_eq!(
+		a.poll_next_unpin(&mut Context::from_waker(&waker_a)),
+		Poll::Ready(Some(1))
+	);
+}
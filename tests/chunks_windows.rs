@@ -0,0 +1,124 @@
+use core::pin::Pin;
+use futures_util::{
+	future::FutureExt as _,
+	stream::{self, StreamExt as _},
+};
+use percolate::stream::{chunks, windows, Chunk, PartialChunk};
+use pollster::block_on;
+use std::rc::Rc;
+
+#[test]
+fn chunks_drop_their_partial_buffer() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	let input = stream::iter((1..=5).map(|_| Rc::clone(&value)))
+		.chain(stream::pending())
+		.fuse();
+	let mut chunks = chunks::<_, 3>(input);
+
+	let full = chunks
+		.next()
+		.now_or_never()
+		.flatten()
+		.unwrap()
+		.into_full()
+		.unwrap();
+	assert!(chunks.next().now_or_never().is_none());
+	assert_eq!(alive(), 5);
+
+	drop(full);
+	assert_eq!(alive(), 2);
+	drop(chunks);
+	assert_eq!(alive(), 0);
+}
+
+#[test]
+fn partial_chunk_iterators_drop_the_rest() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	let mut chunks: Vec<Chunk<_, 4>> =
+		block_on(chunks(stream::iter((1..=6).map(|i| (i, Rc::clone(&value)))).fuse()).collect());
+	let partial = match chunks.pop() {
+		Some(Chunk::Partial(partial)) => partial,
+		_ => panic!("expected a partial chunk"),
+	};
+	drop(chunks);
+	assert_eq!(alive(), 2);
+
+	let mut iter = partial.into_iter();
+	assert_eq!(iter.next_back().unwrap().0, 6);
+	assert_eq!(alive(), 1);
+	drop(iter);
+	assert_eq!(alive(), 0);
+}
+
+#[test]
+fn partial_chunk_drops_each_item_once() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	let mut partial = PartialChunk::<_, 3>::new();
+	partial.push((1, Rc::clone(&value))).unwrap();
+	partial.push((2, Rc::clone(&value))).unwrap();
+	assert_eq!(partial.pop().unwrap().0, 2);
+	assert_eq!(alive(), 1);
+
+	let partial = partial.into_array().unwrap_err();
+	assert_eq!(alive(), 1);
+	drop(partial);
+	assert_eq!(alive(), 0);
+}
+
+#[test]
+fn windows_drop_items_as_they_slide_out() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	let input = stream::iter((1..=4).map(|i| (i, Rc::clone(&value))))
+		.chain(stream::pending())
+		.fuse();
+	let mut windows = windows::<_, 3>(input);
+
+	let window = Pin::new(&mut windows)
+		.next_window()
+		.now_or_never()
+		.flatten()
+		.unwrap();
+	assert_eq!(
+		window.iter().map(|item| item.0).collect::<Vec<_>>(),
+		[1, 2, 3]
+	);
+	assert_eq!(alive(), 3);
+	let window = Pin::new(&mut windows)
+		.next_window()
+		.now_or_never()
+		.flatten()
+		.unwrap();
+	assert_eq!(
+		window.iter().map(|item| item.0).collect::<Vec<_>>(),
+		[2, 3, 4]
+	);
+	assert_eq!(alive(), 3);
+	assert!(Pin::new(&mut windows)
+		.next_window()
+		.now_or_never()
+		.is_none());
+
+	drop(windows);
+	assert_eq!(alive(), 0);
+}
+
+#[test]
+fn windows_drop_a_short_input() {
+	let value = Rc::new(());
+	let input = stream::iter((1..=2).map(|_| Rc::clone(&value)))
+		.chain(stream::pending())
+		.fuse();
+	let mut windows = windows::<_, 3>(input);
+
+	assert!(Pin::new(&mut windows)
+		.next_window()
+		.now_or_never()
+		.is_none());
+	assert_eq!(Rc::strong_count(&value), 3);
+	drop(windows);
+	assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,81 @@
+//! Wraps a projection that only implements [`ProjectionMut`] in [`Fuse`] to use it in [`Either`].
+
+use core::{future::Future, pin::Pin};
+use ergo_pin::ergo_pin;
+use futures_core::FusedFuture;
+use percolate::{
+	handles::PinHandleMut,
+	projection::{
+		from_ref_blocking_mut, Either, Fuse, FusedPollProjectionMut, FusedRefProjectionMut,
+		IntoFusedRefProjectionMut, ProjectionMut,
+	},
+};
+use pollster::block_on;
+
+/// Implements only [`ProjectionMut`], like projections written against earlier versions of this crate.
+struct Length<P>(P);
+
+impl<'a, P> ProjectionMut<&'a str, usize> for Length<P>
+where
+	P: ProjectionMut<&'a str, usize>,
+{
+	fn project(
+		self: Pin<&mut Self>,
+		value: &'a str,
+	) -> PinHandleMut<'_, dyn '_ + Future<Output = usize>> {
+		unsafe { self.map_unchecked_mut(|this| &mut this.0) }.project(value)
+	}
+}
+
+impl<'a, P> ProjectionMut<&'a mut str, usize> for Length<P>
+where
+	P: ProjectionMut<&'a mut str, usize>,
+{
+	fn project(
+		self: Pin<&mut Self>,
+		value: &'a mut str,
+	) -> PinHandleMut<'_, dyn '_ + Future<Output = usize>> {
+		unsafe { self.map_unchecked_mut(|this| &mut this.0) }.project(value)
+	}
+}
+
+#[ergo_pin]
+async fn project_ref_fused<A: ?Sized, B, X>(
+	value: &A,
+	projection: impl IntoFusedRefProjectionMut<A, B, X>,
+) -> B {
+	pin!(projection.into_fused_ref_projection_mut())
+		.project_ref_fused(value)
+		.await
+}
+
+async fn length_or_count(bytes: bool, value: &str) -> usize {
+	let projection = if bytes {
+		Either::Left(Fuse::new(Length(from_ref_blocking_mut(|s: &str| s.len()))))
+	} else {
+		Either::Right(from_ref_blocking_mut(|s: &str| s.chars().count()))
+	};
+	project_ref_fused(value, projection).await
+}
+
+#[test]
+fn ref_projection_in_either() {
+	assert_eq!(block_on(length_or_count(true, "ä")), 2);
+	assert_eq!(block_on(length_or_count(false, "ä")), 1);
+}
+
+#[test]
+#[ergo_pin]
+fn terminated_unless_in_progress() {
+	let mut fuse = pin!(Fuse::new(Length(from_ref_blocking_mut(|s: &str| s.len()))));
+	assert!(FusedPollProjectionMut::<&str, usize>::is_terminated(&*fuse));
+
+	let mut future = fuse.as_mut().project_ref_fused("abc");
+	assert!(!future.is_terminated());
+	assert_eq!(block_on(&mut future), 3);
+	assert!(future.is_terminated());
+	drop(future);
+
+	drop(fuse.as_mut().project_ref_fused("abc"));
+	assert!(FusedPollProjectionMut::<&str, usize>::is_terminated(&*fuse));
+}
@@ -0,0 +1,263 @@
+//! Converts blocking and async closures through each `Into…` trait in generic code only,
+//! which checks that the traits' associated types and conversion methods fit together.
+//!
+//! Nothing in this crate implements the shared (<code><s>Mut</s></code>) projection traits by itself yet,
+//! so their `Into…` traits are only type-checked.
+
+use ergo_pin::ergo_pin;
+use futures_core::FusedFuture;
+use futures_util::future::ready;
+use percolate::{
+	predicate::{
+		FusedMutPredicateMut, FusedPredicateMut, IntoFusedMutPredicateMut, IntoFusedPredicateMut,
+		IntoMutPredicateMut, IntoPredicateMut, MutPredicateMut, PredicateMut,
+	},
+	projection::{
+		fuse_async_mut, fuse_ref_async_mut, AsyncMut, FusedMutProjection, FusedMutProjectionMut,
+		FusedProjection, FusedProjectionMut, FusedRefProjection, FusedRefProjectionMut,
+		IntoFusedMutProjection, IntoFusedMutProjectionMut, IntoFusedProjection,
+		IntoFusedProjectionMut, IntoFusedRefProjection, IntoFusedRefProjectionMut,
+		IntoMutProjection, IntoMutProjectionMut, IntoProjection, IntoProjectionMut,
+		IntoRefProjection, IntoRefProjectionMut, MutProjection, MutProjectionMut, Projection,
+		ProjectionMut, RefProjection, RefProjectionMut,
+	},
+};
+use pollster::block_on;
+
+fn fused<F: FusedFuture>(future: F) -> F {
+	future
+}
+
+// region: generic conversions
+
+#[allow(dead_code)]
+#[ergo_pin]
+async fn project<A, B, X>(value: A, projection: impl IntoProjection<A, B, X>) -> B {
+	pin!(projection.into_projection())
+		.into_ref()
+		.project(value)
+		.await
+}
+
+#[allow(dead_code)]
+#[ergo_pin]
+async fn project_fused<A, B, X>(value: A, projection: impl IntoFusedProjection<A, B, X>) -> B {
+	fused(
+		pin!(projection.into_fused_projection())
+			.into_ref()
+			.project_fused(value),
+	)
+	.await
+}
+
+#[allow(dead_code)]
+#[ergo_pin]
+async fn project_ref<A: ?Sized, B, X>(value: &A, projection: impl IntoRefProjection<A, B, X>) -> B {
+	pin!(projection.into_ref_projection())
+		.into_ref()
+		.project_ref(value)
+		.await
+}
+
+#[allow(dead_code)]
+#[ergo_pin]
+async fn project_mut<A: ?Sized, B, X>(
+	value: &mut A,
+	projection: impl IntoMutProjection<A, B, X>,
+) -> B {
+	pin!(projection.into_mut_projection())
+		.into_ref()
+		.project_mut(value)
+		.await
+}
+
+#[allow(dead_code)]
+#[ergo_pin]
+async fn project_ref_fused<A: ?Sized, B, X>(
+	value: &A,
+	projection: impl IntoFusedRefProjection<A, B, X>,
+) -> B {
+	fused(
+		pin!(projection.into_fused_ref_projection())
+			.into_ref()
+			.project_ref_fused(value),
+	)
+	.await
+}
+
+#[allow(dead_code)]
+#[ergo_pin]
+async fn project_mut_fused<A: ?Sized, B, X>(
+	value: &mut A,
+	projection: impl IntoFusedMutProjection<A, B, X>,
+) -> B {
+	fused(
+		pin!(projection.into_fused_mut_projection())
+			.into_ref()
+			.project_mut_fused(value),
+	)
+	.await
+}
+
+#[ergo_pin]
+async fn project_by_mut<A, B, X>(value: A, projection: impl IntoProjectionMut<A, B, X>) -> B {
+	pin!(projection.into_projection_mut()).project(value).await
+}
+
+#[ergo_pin]
+async fn project_fused_by_mut<A, B, X>(
+	value: A,
+	projection: impl IntoFusedProjectionMut<A, B, X>,
+) -> B {
+	fused(pin!(projection.into_fused_projection_mut()).project_fused(value)).await
+}
+
+#[ergo_pin]
+async fn project_ref_by_mut<A: ?Sized, B, X>(
+	value: &A,
+	projection: impl IntoRefProjectionMut<A, B, X>,
+) -> B {
+	pin!(projection.into_ref_projection_mut())
+		.project_ref(value)
+		.await
+}
+
+#[ergo_pin]
+async fn project_mut_by_mut<A: ?Sized, B, X>(
+	value: &mut A,
+	projection: impl IntoMutProjectionMut<A, B, X>,
+) -> B {
+	pin!(projection.into_mut_projection_mut())
+		.project_mut(value)
+		.await
+}
+
+#[ergo_pin]
+async fn project_ref_fused_by_mut<A: ?Sized, B, X>(
+	value: &A,
+	projection: impl IntoFusedRefProjectionMut<A, B, X>,
+) -> B {
+	fused(pin!(projection.into_fused_ref_projection_mut()).project_ref_fused(value)).await
+}
+
+#[ergo_pin]
+async fn project_mut_fused_by_mut<A: ?Sized, B, X>(
+	value: &mut A,
+	projection: impl IntoFusedMutProjectionMut<A, B, X>,
+) -> B {
+	fused(pin!(projection.into_fused_mut_projection_mut()).project_mut_fused(value)).await
+}
+
+#[ergo_pin]
+async fn test<T: ?Sized, X>(value: &T, predicate: impl IntoPredicateMut<T, X>) -> bool {
+	PredicateMut::test(pin!(predicate.into_predicate_mut()), value).await
+}
+
+#[ergo_pin]
+async fn test_mut<T: ?Sized, X>(value: &mut T, predicate: impl IntoMutPredicateMut<T, X>) -> bool {
+	MutPredicateMut::test_mut(pin!(predicate.into_mut_predicate_mut()), value).await
+}
+
+#[ergo_pin]
+async fn test_fused<T: ?Sized, X>(value: &T, predicate: impl IntoFusedPredicateMut<T, X>) -> bool {
+	fused(FusedPredicateMut::test(
+		pin!(predicate.into_fused_predicate_mut()),
+		value,
+	))
+	.await
+}
+
+#[ergo_pin]
+async fn test_mut_fused<T: ?Sized, X>(
+	value: &mut T,
+	predicate: impl IntoFusedMutPredicateMut<T, X>,
+) -> bool {
+	fused(FusedMutPredicateMut::test(
+		pin!(predicate.into_fused_mut_predicate_mut()),
+		value,
+	))
+	.await
+}
+
+// endregion
+
+#[test]
+fn blocking() {
+	assert_eq!(block_on(project_by_mut(1, |x: u8| x + 1)), 2);
+	assert_eq!(block_on(project_fused_by_mut(1, |x: u8| x + 1)), 2);
+
+	assert_eq!(block_on(project_ref_by_mut(&1, |x: &u8| x + 1)), 2);
+	assert_eq!(block_on(project_mut_by_mut(&mut 1, |x: &u8| x + 1)), 2);
+	assert_eq!(block_on(project_ref_fused_by_mut(&1, |x: &u8| x + 1)), 2);
+	assert_eq!(
+		block_on(project_mut_fused_by_mut(&mut 1, |x: &u8| x + 1)),
+		2
+	);
+
+	let increment = |x: &mut u8| {
+		*x += 1;
+		*x
+	};
+	assert_eq!(block_on(project_mut_by_mut(&mut 1, increment)), 2);
+	assert_eq!(block_on(project_mut_fused_by_mut(&mut 1, increment)), 2);
+
+	assert!(block_on(test(&1, |x: &u8| *x == 1)));
+	assert!(block_on(test_mut(&mut 1, |x: &u8| *x == 1)));
+	assert!(block_on(test_fused(&1, |x: &u8| *x == 1)));
+	assert!(block_on(test_mut_fused(&mut 1, |x: &u8| *x == 1)));
+}
+
+#[test]
+fn asynchronous() {
+	assert_eq!(
+		block_on(project_by_mut::<_, _, AsyncMut<_, _, _, _>>(
+			1,
+			|x: u8| async move { x + 1 }
+		)),
+		2,
+	);
+	assert_eq!(
+		block_on(project_fused_by_mut::<_, _, AsyncMut<_, _, _, _>>(
+			1,
+			|x: u8| ready(x + 1)
+		)),
+		2,
+	);
+
+	assert_eq!(
+		block_on(project_by_mut(
+			1,
+			fuse_async_mut(|x: u8| async move { x + 1 })
+		)),
+		2,
+	);
+	assert_eq!(
+		block_on(project_fused_by_mut(
+			1,
+			fuse_async_mut(|x: u8| async move { x + 1 }),
+		)),
+		2,
+	);
+
+	let increment = || {
+		fuse_ref_async_mut(|x: &u8| {
+			let x = *x;
+			async move { x + 1 }
+		})
+	};
+	assert_eq!(block_on(project_ref_by_mut(&1, increment())), 2);
+	assert_eq!(block_on(project_mut_by_mut(&mut 1, increment())), 2);
+	assert_eq!(block_on(project_ref_fused_by_mut(&1, increment())), 2);
+	assert_eq!(block_on(project_mut_fused_by_mut(&mut 1, increment())), 2);
+
+	let is_one = || {
+		fuse_ref_async_mut(|x: &u8| {
+			let x = *x;
+			async move { x == 1 }
+		})
+	};
+	assert!(block_on(test(&1, is_one())));
+	assert!(block_on(test_mut(&mut 1, is_one())));
+	assert!(block_on(test_fused(&1, is_one())));
+	assert!(block_on(test_mut_fused(&mut 1, is_one())));
+}
@@ -0,0 +1,101 @@
+use percolate::projection::{Cache, LruCache};
+use std::{
+	cell::Cell,
+	panic::{self, AssertUnwindSafe},
+	rc::Rc,
+};
+
+#[test]
+fn evicts_least_recently_used() {
+	let mut cache = LruCache::<_, _, 2>::new();
+	cache.insert(1, 'a');
+	cache.insert(2, 'b');
+	assert_eq!(cache.get(&1), Some(&'a'));
+
+	cache.insert(3, 'c');
+	assert_eq!(cache.len(), 2);
+	assert_eq!(cache.get(&2), None);
+	assert_eq!(cache.get(&1), Some(&'a'));
+	assert_eq!(cache.get(&3), Some(&'c'));
+
+	// `3` was used last, so `1` goes.
+	cache.insert(4, 'd');
+	assert_eq!(cache.get(&1), None);
+	assert_eq!(cache.get(&3), Some(&'c'));
+	assert_eq!(cache.get(&4), Some(&'d'));
+}
+
+#[test]
+fn reinserting_refreshes() {
+	let mut cache = LruCache::<_, _, 2>::new();
+	cache.insert(1, 'a');
+	cache.insert(2, 'b');
+	cache.insert(1, 'A');
+	assert_eq!(cache.len(), 2);
+
+	cache.insert(3, 'c');
+	assert_eq!(cache.get(&2), None);
+	assert_eq!(cache.get(&1), Some(&'A'));
+}
+
+#[test]
+fn drops_each_entry_once() {
+	let value = Rc::new(());
+	let alive = || Rc::strong_count(&value) - 1;
+	{
+		let mut cache = LruCache::<_, _, 2>::new();
+		cache.insert(1, Rc::clone(&value));
+		cache.insert(2, Rc::clone(&value));
+		cache.insert(2, Rc::clone(&value));
+		assert_eq!(alive(), 2);
+
+		cache.insert(3, Rc::clone(&value));
+		assert_eq!(alive(), 2);
+
+		cache.clear();
+		assert!(cache.is_empty());
+		assert_eq!(alive(), 0);
+
+		cache.insert(4, Rc::clone(&value));
+		assert_eq!(alive(), 1);
+	}
+	assert_eq!(alive(), 0);
+}
+
+#[test]
+fn zero_capacity_drops_immediately() {
+	let value = Rc::new(());
+	let mut cache = LruCache::<_, _, 0>::new();
+	cache.insert(1, Rc::clone(&value));
+	assert_eq!(Rc::strong_count(&value), 1);
+	assert!(cache.get(&1).is_none());
+}
+
+/// Counts its drops, and panics during the first one.
+struct PanicOnDrop(Rc<Cell<usize>>);
+
+impl Drop for PanicOnDrop {
+	fn drop(&mut self) {
+		self.0.set(self.0.get() + 1);
+		if self.0.get() == 1 {
+			panic!("first drop");
+		}
+	}
+}
+
+#[test]
+fn replaced_value_is_dropped_once_even_if_that_panics() {
+	let drops = Rc::new(Cell::new(0));
+	let mut cache = LruCache::<_, _, 2>::new();
+	cache.insert(1, PanicOnDrop(Rc::clone(&drops)));
+
+	let replaced = panic::catch_unwind(AssertUnwindSafe(|| {
+		cache.insert(1, PanicOnDrop(Rc::clone(&drops)));
+	}));
+	assert!(replaced.is_err());
+	assert_eq!(drops.get(), 1);
+	assert_eq!(cache.len(), 1);
+
+	drop(cache);
+	assert_eq!(drops.get(), 2);
+}
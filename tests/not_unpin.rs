@@ -0,0 +1,41 @@
+//! Adapters that keep a projection's future next to the projection it borrows must not be movable once pinned.
+
+use core::time::Duration;
+use futures_util::stream::{Fuse as StreamFuse, Iter};
+use percolate::{
+	predicate,
+	projection::{
+		Branch, FanOut, FixedBackoff, Fuse, FusedBlockingMut, FusedRefBlockingMut, LruCache,
+		Memoize, Retry, Select, SelectAll, Timeout,
+	},
+	stream::{ProjectBufferUnordered, ProjectBuffered},
+	time::ManualClock,
+};
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+type Input = StreamFuse<Iter<core::ops::Range<u32>>>;
+type Double = FusedBlockingMut<fn(u32) -> u32, u32, u32>;
+type Fallible = FusedBlockingMut<fn(u32) -> Result<u32, ()>, u32, Result<u32, ()>>;
+type RefDouble = FusedRefBlockingMut<fn(&u32) -> u32, u32, u32>;
+type IsEven = predicate::FusedBlockingMut<fn(&u32) -> bool, u32>;
+type Clock = &'static ManualClock<1>;
+
+// The parts themselves can be moved freely.
+assert_impl_all!(Input: Unpin);
+assert_impl_all!(Double: Unpin);
+assert_impl_all!(Fallible: Unpin);
+assert_impl_all!(RefDouble: Unpin);
+assert_impl_all!(IsEven: Unpin);
+assert_impl_all!(Clock: Unpin);
+
+assert_not_impl_any!(ProjectBuffered<Input, Double, u32, 2>: Unpin);
+assert_not_impl_any!(ProjectBufferUnordered<Input, Double, u32, 2>: Unpin);
+
+assert_not_impl_any!(Memoize<Double, LruCache<u32, u32, 2>, u32, u32>: Unpin);
+assert_not_impl_any!(Timeout<Double, Clock, u32, u32>: Unpin);
+assert_not_impl_any!(Retry<Fallible, FixedBackoff<Duration>, Clock, u32, u32, ()>: Unpin);
+assert_not_impl_any!(Branch<IsEven, RefDouble, RefDouble, u32, u32>: Unpin);
+assert_not_impl_any!(FanOut<(RefDouble, RefDouble), u32, (u32, u32)>: Unpin);
+assert_not_impl_any!(Select<RefDouble, RefDouble, u32, u32, u32>: Unpin);
+assert_not_impl_any!(SelectAll<RefDouble, u32, u32, 2>: Unpin);
+assert_not_impl_any!(Fuse<Double, u32>: Unpin);
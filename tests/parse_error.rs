@@ -0,0 +1,109 @@
+use ergo_pin::ergo_pin;
+use futures_util::stream::{self, StreamExt as _};
+use percolate::{
+	parse::{choice, many, sequence, token, Cursor, ParseError, LOOKAHEAD_EXCEEDED},
+	predicate::ops::eq,
+	stream::PeekStream,
+};
+use pollster::block_on;
+
+fn labels(error: &ParseError) -> Vec<&'static str> {
+	error.expected().iter().collect()
+}
+
+#[test]
+fn merge_unites_labels_at_the_same_position() {
+	let merged = ParseError::new(1, "a")
+		.merge(ParseError::new(1, "b"))
+		.merge(ParseError::new(1, "a"));
+	assert_eq!(merged.position(), 1);
+	assert_eq!(labels(&merged), ["a", "b"]);
+	assert_eq!(merged.to_string(), "at position 1: expected a or b");
+}
+
+#[test]
+fn merge_keeps_the_furthest_error() {
+	let near = ParseError::new(1, "near");
+	let far = ParseError::new(2, "far");
+	assert_eq!(near.merge(far), far);
+	assert_eq!(far.merge(near), far);
+}
+
+#[test]
+fn merge_retains_the_first_eight_labels() {
+	let names = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+	let merged = names
+		.iter()
+		.map(|name| ParseError::new(0, name))
+		.fold(ParseError::new(0, "0"), ParseError::merge);
+	assert_eq!(labels(&merged), names[..8]);
+	assert!(!merged.expected().contains("8"));
+}
+
+#[test]
+fn expected_ignores_order() {
+	let ab = ParseError::new(0, "a").merge(ParseError::new(0, "b"));
+	let ba = ParseError::new(0, "b").merge(ParseError::new(0, "a"));
+	assert_eq!(ab, ba);
+}
+
+#[ergo_pin]
+async fn parse(input: &str) -> Result<(), ParseError> {
+	let stream = pin!(PeekStream::<_, 4>::new(stream::iter(input.chars()).fuse()));
+	let (_, result) = choice(
+		Cursor::new(stream),
+		|cursor| {
+			sequence(
+				cursor,
+				|c| token(c, "`a`", eq('a')),
+				|c| token(c, "`b`", eq('b')),
+			)
+		},
+		|cursor| {
+			sequence(
+				cursor,
+				|c| token(c, "`a`", eq('a')),
+				|c| token(c, "`c`", eq('c')),
+			)
+		},
+	)
+	.await;
+	result.map(drop)
+}
+
+#[test]
+fn choice_merges_alternatives() {
+	assert_eq!(block_on(parse("ab")), Ok(()));
+	assert_eq!(block_on(parse("ac")), Ok(()));
+
+	let error = block_on(parse("ad")).unwrap_err();
+	assert_eq!(error.position(), 1);
+	assert_eq!(labels(&error), ["`b`", "`c`"]);
+
+	let error = block_on(parse("x")).unwrap_err();
+	assert_eq!(error.position(), 0);
+	assert_eq!(labels(&error), ["`a`"]);
+}
+
+#[ergo_pin]
+async fn count_a(input: &str) -> Result<usize, ParseError> {
+	let stream = pin!(PeekStream::<_, 2>::new(stream::iter(input.chars()).fuse()));
+	let (_, result) = many(
+		Cursor::new(stream),
+		|c| token(c, "`a`", eq('a')),
+		0,
+		|count, _| count + 1,
+	)
+	.await;
+	result
+}
+
+#[test]
+fn exceeding_the_lookahead_fails_instead_of_backtracking() {
+	assert_eq!(block_on(count_a("ab")), Ok(1));
+
+	// Telling where `many` ends takes a third item of lookahead.
+	let error = block_on(count_a("aab")).unwrap_err();
+	assert_eq!(error.position(), 2);
+	assert_eq!(labels(&error), [LOOKAHEAD_EXCEEDED]);
+}
@@ -0,0 +1,199 @@
+//! Checks [`PeekStream`] against a [`VecDeque`] model for pseudo-random interleavings of its operations.
+//!
+//! The item count and number of operations are reduced under Miri.
+
+use core::num::NonZeroUsize;
+use futures_core::Stream;
+use futures_util::{
+	future::FutureExt as _,
+	stream::{self, StreamExt as _},
+};
+use percolate::stream::PeekStream;
+use std::{collections::VecDeque, rc::Rc, vec::Vec};
+
+const CASES: u64 = if cfg!(miri) { 4 } else { 200 };
+const OPERATIONS: usize = if cfg!(miri) { 24 } else { 96 };
+const ITEMS: u32 = if cfg!(miri) { 12 } else { 48 };
+
+/// An item that keeps `token` alive, so that leaks and double drops are observable.
+#[derive(Debug)]
+struct Item {
+	value: u32,
+	_token: Rc<()>,
+}
+
+impl PartialEq<u32> for Item {
+	fn eq(&self, other: &u32) -> bool {
+		self.value == *other
+	}
+}
+
+/// xorshift64*, to stay deterministic and free of dependencies.
+struct Rng(u64);
+impl Rng {
+	fn next(&mut self) -> u64 {
+		self.0 ^= self.0 >> 12;
+		self.0 ^= self.0 << 25;
+		self.0 ^= self.0 >> 27;
+		self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+	}
+
+	fn below(&mut self, bound: usize) -> usize {
+		(self.next() % bound as u64) as usize
+	}
+}
+
+fn run<const CAPACITY: usize>(seed: u64) {
+	let token = Rc::new(());
+	let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1);
+
+	let len = rng.below(ITEMS as usize + 1) as u32;
+	let mut model: VecDeque<u32> = (0..len).collect();
+	let mut stream = Box::pin(PeekStream::<_, CAPACITY>::new(
+		stream::iter(
+			(0..len)
+				.map(|value| Item {
+					value,
+					_token: token.clone(),
+				})
+				.collect::<Vec<_>>(),
+		)
+		.fuse(),
+	));
+
+	for step in 0..OPERATIONS {
+		let context = || format!("seed {}, CAPACITY {}, step {}", seed, CAPACITY, step);
+		let depth = NonZeroUsize::new(rng.below(CAPACITY) + 1).unwrap();
+		match rng.below(8) {
+			0 => {
+				let peeked = ready(stream.as_mut().peek_n(depth)).map(|item| item.value);
+				assert_eq!(
+					peeked,
+					model.get(depth.get() - 1).copied(),
+					"peek_n: {}",
+					context()
+				);
+			}
+			1 => {
+				let peeked = ready(stream.as_mut().peek_n_mut(depth)).map(|item| {
+					item.value += 1000;
+					item.value
+				});
+				let expected = model.get_mut(depth.get() - 1).map(|value| {
+					*value += 1000;
+					*value
+				});
+				assert_eq!(peeked, expected, "peek_n_mut: {}", context());
+			}
+			2 => {
+				let next = ready(stream.next()).map(|item| item.value);
+				assert_eq!(next, model.pop_front(), "next: {}", context());
+			}
+			3 => {
+				let next = ready(stream.as_mut().next_if(|item: &Item| item.value % 2 == 0))
+					.map(|item| item.value);
+				let expected = if model.front().map_or(false, |value| value % 2 == 0) {
+					model.pop_front()
+				} else {
+					None
+				};
+				assert_eq!(next, expected, "next_if: {}", context());
+			}
+			4 => {
+				let next = ready(stream.as_mut().next_if_mut(|item: &mut Item| {
+					item.value += 1;
+					item.value % 3 == 0
+				}))
+				.map(|item| item.value);
+				let expected = model.front_mut().and_then(|value| {
+					*value += 1;
+					if *value % 3 == 0 {
+						Some(*value)
+					} else {
+						None
+					}
+				});
+				if expected.is_some() {
+					model.pop_front();
+				}
+				assert_eq!(next, expected, "next_if_mut: {}", context());
+			}
+			5 => {
+				let expected = if rng.below(2) == 0 {
+					model.front().copied().unwrap_or(0)
+				} else {
+					u32::MAX
+				};
+				let next = ready(stream.as_mut().next_if_eq(&expected)).map(|item| item.value);
+				let model_next = if model.front() == Some(&expected) {
+					model.pop_front()
+				} else {
+					None
+				};
+				assert_eq!(next, model_next, "next_if_eq: {}", context());
+			}
+			6 => {
+				assert_eq!(
+					Stream::size_hint(&*stream),
+					(model.len(), Some(model.len())),
+					"size_hint: {}",
+					context()
+				);
+			}
+			7 => {
+				let modulus = rng.below(4) as u32 + 2;
+				let position = ready(
+					stream
+						.as_mut()
+						.peek_position(|item: &Item| item.value % modulus == 0),
+				)
+				.map(NonZeroUsize::get);
+				let expected = model
+					.iter()
+					.take(CAPACITY)
+					.position(|value| value % modulus == 0)
+					.map(|index| index + 1);
+				assert_eq!(position, expected, "peek_position: {}", context());
+			}
+			_ => unreachable!(),
+		}
+	}
+
+	drop(stream);
+	assert_eq!(
+		Rc::strong_count(&token),
+		1,
+		"leaked or double-dropped items: seed {}, CAPACITY {}",
+		seed,
+		CAPACITY
+	);
+}
+
+/// The input is always ready, so every operation completes on its first poll.
+fn ready<T>(future: impl core::future::Future<Output = T>) -> T {
+	future
+		.now_or_never()
+		.expect("operation didn't complete synchronously")
+}
+
+macro_rules! capacities {
+	($($name:ident: $capacity:literal),*$(,)?) => {$(
+		#[test]
+		fn $name() {
+			for seed in 0..CASES {
+				run::<$capacity>(seed);
+			}
+		}
+	)*};
+}
+
+capacities! {
+	capacity_1: 1,
+	capacity_2: 2,
+	capacity_3: 3,
+	capacity_4: 4,
+	capacity_5: 5,
+	capacity_7: 7,
+	capacity_8: 8,
+	capacity_16: 16,
+}
@@ -0,0 +1,81 @@
+use core::num::NonZeroUsize;
+use ergo_pin::ergo_pin;
+use futures_util::stream::{self, StreamExt as _};
+use percolate::stream::{ByteOffset, LineColumn, PeekStream, Position, Positioned};
+use pollster::block_on;
+
+fn line_columns(input: &str) -> Vec<(usize, usize)> {
+	block_on(
+		Positioned::<_, LineColumn>::new(stream::iter(input.chars()))
+			.map(|(position, _)| (position.line, position.column))
+			.collect(),
+	)
+}
+
+#[test]
+fn crlf_is_one_line_break() {
+	assert_eq!(
+		line_columns("a\r\nb\r\n\r\nc"),
+		[
+			(1, 1),
+			(1, 2),
+			(1, 3),
+			(2, 1),
+			(2, 2),
+			(2, 3),
+			(3, 1),
+			(3, 2),
+			(4, 1),
+		]
+	);
+}
+
+#[test]
+fn lone_cr_stays_on_the_line() {
+	assert_eq!(line_columns("a\rb"), [(1, 1), (1, 2), (1, 3)]);
+}
+
+#[test]
+fn line_break_spans_to_next_line() {
+	let start = LineColumn { line: 1, column: 3 };
+	assert_eq!(start.after(&'\r'), LineColumn { line: 1, column: 4 });
+	assert_eq!(start.after(&'\n'), LineColumn { line: 2, column: 1 });
+}
+
+#[ergo_pin]
+async fn spans<Pos: Default + Position<char>>(input: &str) -> Vec<(Pos, Pos)> {
+	let mut stream = pin!(PeekStream::<_, 1>::new(Positioned::<_, Pos>::new(
+		stream::iter(input.chars()).fuse(),
+	)));
+	let mut spans = vec![];
+	while let Some((span, _)) = stream
+		.as_mut()
+		.peek_n_span(NonZeroUsize::new(1).unwrap())
+		.await
+	{
+		spans.push((span.start, span.end));
+		stream.next().await;
+	}
+	spans
+}
+
+#[test]
+fn crlf_spans() {
+	let at = |line, column| LineColumn { line, column };
+	assert_eq!(
+		block_on(spans::<LineColumn>("\r\nä")),
+		[
+			(at(1, 1), at(1, 2)),
+			(at(1, 2), at(2, 1)),
+			(at(2, 1), at(2, 2)),
+		]
+	);
+	assert_eq!(
+		block_on(spans::<ByteOffset>("\r\nä")),
+		[
+			(ByteOffset(0), ByteOffset(1)),
+			(ByteOffset(1), ByteOffset(2)),
+			(ByteOffset(2), ByteOffset(4)),
+		]
+	);
+}
@@ -0,0 +1,102 @@
+use core::mem::size_of;
+use ergo_pin::ergo_pin;
+use futures_core::FusedFuture;
+use percolate::{
+	predicate::{
+		bytes,
+		chars::{self, alphabetic, digit, whitespace},
+		ops::{always, contained_in, eq, ge, gt, in_range, is_some, Not, Or},
+		FusedPredicateMut, IntoFusedPredicateMut,
+	},
+	projection::FusedPollProjectionMut,
+};
+use pollster::block_on;
+
+#[test]
+fn classes_are_zero_sized() {
+	assert_eq!(size_of::<chars::Alphabetic>(), 0);
+	assert_eq!(size_of::<bytes::AsciiDigit>(), 0);
+	assert_eq!(size_of::<Not<chars::Whitespace>>(), 0);
+	assert_eq!(size_of::<Or<chars::Alphabetic, chars::Numeric>>(), 0);
+	assert_eq!(size_of_val(&(!always() & is_some())), 0);
+
+	assert_eq!(
+		size_of_val(&(alphabetic() | digit(10))),
+		size_of::<chars::Digit>()
+	);
+}
+
+fn size_of_val<T>(_: &T) -> usize {
+	size_of::<T>()
+}
+
+#[test]
+#[ergo_pin]
+fn terminated_unless_in_progress() {
+	let mut predicate = pin!(IntoFusedPredicateMut::<char, _>::into_fused_predicate_mut(
+		!whitespace()
+	));
+	assert!(FusedPollProjectionMut::<&char, bool>::is_terminated(
+		&*predicate
+	));
+
+	let mut test = predicate.as_mut().test(&'a');
+	assert!(!test.is_terminated());
+	assert!(block_on(&mut test));
+	assert!(test.is_terminated());
+	drop(test);
+
+	let test = predicate.as_mut().test(&' ');
+	assert!(!test.is_terminated());
+	drop(test);
+	assert!(FusedPollProjectionMut::<&char, bool>::is_terminated(
+		&*predicate
+	));
+}
+
+#[test]
+#[should_panic = "must be in `2..=36`"]
+fn radix_one() {
+	let _ = digit(1);
+}
+
+fn assert_fused_predicate<T: ?Sized>(_: impl FusedPredicateMut<T>) {}
+
+#[test]
+fn parameterized_ops_are_predicates() {
+	assert_fused_predicate::<u32>(gt(5u32));
+	assert_fused_predicate::<u32>(ge(5u32));
+	assert_fused_predicate::<&str>(eq("a"));
+	assert_fused_predicate::<u8>(in_range(b'0'..=b'9'));
+	assert_fused_predicate::<i32>(contained_in(&[1, 2, 3]));
+}
+
+#[test]
+#[ergo_pin]
+fn comparisons_track_their_outcome() {
+	let mut predicate = pin!(gt(5u32));
+	assert!(FusedPollProjectionMut::<&u32, bool>::is_terminated(
+		&*predicate
+	));
+
+	let mut test = FusedPredicateMut::test(predicate.as_mut(), &6);
+	assert!(!test.is_terminated());
+	assert!(block_on(&mut test));
+	assert!(test.is_terminated());
+	drop(test);
+
+	assert!(!block_on(FusedPredicateMut::test(predicate.as_mut(), &5)));
+}
+
+fn assert_into_fused_predicate<T: ?Sized, X>(_: impl IntoFusedPredicateMut<T, X>) {}
+
+#[test]
+fn classes_convert_into_predicates() {
+	assert_into_fused_predicate::<char, _>(alphabetic());
+	assert_into_fused_predicate::<char, _>(!whitespace() & alphabetic());
+	assert_into_fused_predicate::<u8, _>(bytes::ascii_digit() | bytes::one_of(b"_"));
+	assert_into_fused_predicate::<u32, _>(always());
+
+	assert_fused_predicate::<char>(digit(10));
+	assert_fused_predicate::<u8>(bytes::none_of(b" "));
+}
@@ -0,0 +1,77 @@
+use core::time::Duration;
+use percolate::projection::{ExponentialBackoff, FixedBackoff, Jitter, RetryPolicy};
+
+fn delays<R: RetryPolicy<(), Duration>>(policy: &mut R, attempts: usize) -> Vec<Option<Duration>> {
+	(1..=attempts)
+		.map(|attempt| policy.retry(attempt, &()))
+		.collect()
+}
+
+#[test]
+fn fixed_gives_up_after_max_attempts() {
+	let delay = Some(Duration::from_secs(1));
+	assert_eq!(
+		delays(&mut FixedBackoff::new(Duration::from_secs(1), 3), 4),
+		[delay, delay, None, None]
+	);
+}
+
+#[test]
+fn exponential_grows_until_max_delay() {
+	let mut policy = ExponentialBackoff {
+		max_delay: Duration::from_millis(500),
+		..ExponentialBackoff::new(Duration::from_millis(100), 5)
+	};
+	assert_eq!(
+		delays(&mut policy, 5),
+		[
+			Some(Duration::from_millis(100)),
+			Some(Duration::from_millis(200)),
+			Some(Duration::from_millis(400)),
+			Some(Duration::from_millis(500)),
+			None,
+		]
+	);
+}
+
+#[test]
+fn exponential_saturates_on_overflow() {
+	let mut policy = ExponentialBackoff::new(Duration::from_secs(1), usize::MAX);
+	let max_delay = policy.max_delay;
+
+	// `factor.pow(32)` overflows `u32`.
+	assert_eq!(policy.retry(33, &()), Some(max_delay));
+	// `initial_delay * factor` overflows `Duration`.
+	policy.initial_delay = Duration::from_secs(u64::MAX / 2 + 1);
+	assert_eq!(policy.retry(2, &()), Some(max_delay));
+	// The exponent doesn't fit into `u32`.
+	#[cfg(target_pointer_width = "64")]
+	assert_eq!(policy.retry(1 << 33, &()), Some(max_delay));
+}
+
+#[test]
+fn jitter_stays_within_the_inner_delay() {
+	for &seed in &[0, 1, 42, u64::MAX] {
+		let inner = ExponentialBackoff::new(Duration::from_millis(10), 64);
+		let mut jitter = Jitter::new(inner, seed);
+		for attempt in 1..64 {
+			let bound = RetryPolicy::<(), _>::retry(&mut inner.clone(), attempt, &()).unwrap();
+			let delay = jitter.retry(attempt, &()).unwrap();
+			assert!(delay < bound);
+		}
+		assert_eq!(jitter.retry(64, &()), None);
+	}
+}
+
+#[test]
+fn jitter_is_deterministic() {
+	let policy = FixedBackoff::new(Duration::from_secs(1), 16);
+	assert_eq!(
+		delays(&mut Jitter::new(policy, 7), 16),
+		delays(&mut Jitter::new(policy, 7), 16)
+	);
+	assert_ne!(
+		delays(&mut Jitter::new(policy, 7), 16),
+		delays(&mut Jitter::new(policy, 8), 16)
+	);
+}
@@ -0,0 +1,83 @@
+use core::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use futures_util::{future::poll_fn, stream::StreamExt as _};
+use percolate::{
+	assert_pending, assert_ready,
+	testing::{block_on, DropTracker, ScriptedStream, Step, Tracked, WakeCounter},
+};
+
+#[test]
+fn block_on_runs_scripted_stream() {
+	let mut stream = ScriptedStream::new(vec![Step::Pending, Step::Ready(1), Step::Pending]);
+	assert_eq!(block_on(stream.next()), Some(1));
+	assert_eq!(block_on(stream.next()), None);
+	assert_eq!(stream.polls(), 4);
+}
+
+#[test]
+#[should_panic = "lost wake-up"]
+fn block_on_detects_lost_wake_up() {
+	block_on(poll_fn(|_| Poll::<()>::Pending));
+}
+
+#[test]
+#[should_panic = "polled after it ended"]
+fn scripted_stream_panics_after_end() {
+	let mut stream = ScriptedStream::<()>::new(vec![]);
+	assert_eq!(block_on(stream.next()), None);
+	block_on(stream.next());
+}
+
+#[test]
+fn assertions_use_given_context() {
+	struct YieldOnce(bool);
+	impl Future for YieldOnce {
+		type Output = &'static str;
+
+		fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+			if self.0 {
+				Poll::Ready("done")
+			} else {
+				self.0 = true;
+				cx.waker().wake_by_ref();
+				Poll::Pending
+			}
+		}
+	}
+
+	let counter = WakeCounter::new();
+	let waker = counter.waker();
+	let mut cx = Context::from_waker(&waker);
+	let mut future = YieldOnce(false);
+	assert_pending!(future, &mut cx);
+	assert_eq!(counter.count(), 1);
+	assert_eq!(assert_ready!(future), "done");
+	assert_eq!(counter.count(), 1);
+	drop(waker);
+	assert_eq!(counter.wakers(), 0);
+}
+
+#[test]
+#[should_panic = "is ready, but it is pending"]
+fn assert_ready_panics_on_pending() {
+	let mut future = poll_fn(|_| Poll::<()>::Pending);
+	assert_ready!(future);
+}
+
+#[test]
+fn drop_tracker_counts_clones_and_unwrapping() {
+	let tracker = DropTracker::new();
+	let first = tracker.track(String::from("a"));
+	let second = first.clone();
+	assert_eq!(tracker.alive(), 2);
+	assert_eq!(Tracked::into_inner(first), "a");
+	assert_eq!(second, "a");
+	drop(second);
+	assert_eq!(
+		(tracker.created(), tracker.dropped(), tracker.alive()),
+		(2, 2, 0)
+	);
+}
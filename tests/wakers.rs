@@ -0,0 +1,494 @@
+//! Checks that each adapter forwards wake-ups when the futures and streams it drives are [`Pending`](`Poll::Pending`),
+//! and that fused futures neither report termination early nor poll their inner futures after completion.
+//!
+//! [`ScriptedFuture`], [`ScriptedProjection`] and [`ScriptedStream`] wake the [`Waker`](`core::task::Waker`) they are polled with,
+//! so a wake-up is lost iff an adapter polls them with a different [`Context`].
+//! They also panic if polled again after completing.
+
+use core::{
+	num::NonZeroUsize,
+	task::{Context, Poll},
+	time::Duration,
+};
+use ergo_pin::ergo_pin;
+use futures_core::FusedFuture;
+use futures_util::{
+	future::{join, pending},
+	stream::StreamExt as _,
+};
+use percolate::{
+	parse::{separated_by, token, Cursor},
+	predicate::{chars::ascii_digit, ops::eq},
+	projection::{
+		branch, fan_out, from_async_mut, from_blocking_mut, from_mut_blocking_mut,
+		from_ref_blocking_mut, fuse_async_mut, fuse_ref_async_mut, memoize, retry, select,
+		select_all, timeout, try_fan_out, Either, FixedBackoff, FusedProjectionMut, LruCache,
+		ProjectionMut,
+	},
+	stream::{
+		chunks, project_buffer_unordered, project_buffered, windows, Broadcast, ItemIndex,
+		Peek1Stream, PeekStream, Positioned, VecBroadcast,
+	},
+	testing::{
+		block_on, poll_once, ScriptedFuture, ScriptedProjection, ScriptedStream, Step, WakeCounter,
+	},
+	time::{Elapsed, ManualClock},
+};
+
+/// How often each injected await point is pending, at most.
+const PENDING: usize = 3;
+
+/// Polls `future` to completion, asserting that each [`Poll::Pending`] comes with a wake-up
+/// and that it reports termination exactly once it completed.
+fn drive<F: FusedFuture + Unpin>(future: F) -> F::Output {
+	drive_with_clock(future, &ManualClock::<1>::new(), Duration::from_secs(0))
+}
+
+/// Like [`drive`], but advances `clock` by `step` whenever `future` is pending without having been woken yet.
+/// That must then wake it.
+fn drive_with_clock<F: FusedFuture + Unpin, const WAKERS: usize>(
+	mut future: F,
+	clock: &ManualClock<WAKERS>,
+	step: Duration,
+) -> F::Output {
+	let counter = WakeCounter::new();
+	let waker = counter.waker();
+	let mut cx = Context::from_waker(&waker);
+	loop {
+		assert!(!future.is_terminated(), "terminated before completion");
+		let before = counter.count();
+		match poll_once(&mut future, &mut cx) {
+			Poll::Ready(output) => {
+				assert!(future.is_terminated(), "not terminated after completion");
+				return output;
+			}
+			Poll::Pending if counter.count() > before => (),
+			Poll::Pending => {
+				clock.advance(step);
+				assert!(counter.count() > before, "lost wake-up");
+			}
+		}
+	}
+}
+
+// region: projections
+
+#[test]
+fn async_mut() {
+	for pending in 0..=PENDING {
+		let mut projection = Box::pin(from_async_mut(move |x: u32| {
+			ScriptedFuture::new(pending, x * 2)
+		}));
+		for x in 0..3 {
+			assert_eq!(block_on(projection.as_mut().project(x)), x * 2);
+			assert_eq!(drive(projection.as_mut().project_fused(x)), x * 2);
+		}
+	}
+}
+
+#[test]
+fn fuse_async_mut_() {
+	for pending in 0..=PENDING {
+		let mut by_value = Box::pin(fuse_async_mut(move |x: u32| async move {
+			ScriptedFuture::new(pending, x * 2).await
+		}));
+		let mut by_ref = Box::pin(fuse_ref_async_mut(move |x: &u32| {
+			let x = *x;
+			async move { ScriptedFuture::new(pending, x * 2).await }
+		}));
+		for x in 0..3 {
+			assert_eq!(drive(by_value.as_mut().project_fused(x)), x * 2);
+			assert_eq!(drive(by_ref.as_mut().project_fused(&x)), x * 2);
+		}
+	}
+}
+
+#[test]
+fn blocking() {
+	let mut by_value = Box::pin(from_blocking_mut(|x: u32| x + 1));
+	assert_eq!(drive(by_value.as_mut().project_fused(1)), 2);
+
+	let mut by_mut = Box::pin(from_mut_blocking_mut(|x: &mut u32| {
+		*x += 1;
+		*x
+	}));
+	let mut value = 1;
+	assert_eq!(drive(by_mut.as_mut().project_fused(&mut value)), 2);
+
+	let mut by_ref = Box::pin(from_ref_blocking_mut(|x: &u32| x + 1));
+	assert_eq!(drive(by_ref.as_mut().project_fused(&1)), 2);
+}
+
+#[test]
+fn branch_() {
+	for test in 0..=PENDING {
+		for left in 0..=PENDING {
+			for right in 0..=PENDING {
+				let mut projection = Box::pin(branch(
+					ScriptedProjection::new(test, |x: &i32| *x < 0),
+					ScriptedProjection::new(left, |x: &i32| -x),
+					ScriptedProjection::new(right, |x: &i32| x + 100),
+				));
+				assert_eq!(drive(projection.as_mut().project_fused(&-1)), 1);
+				assert_eq!(drive(projection.as_mut().project_fused(&1)), 101);
+				assert_eq!(block_on(projection.as_mut().project(&-2)), 2);
+			}
+		}
+	}
+}
+
+#[test]
+fn either() {
+	for pending in 0..=PENDING {
+		let double = move |x: u32| ScriptedFuture::new(pending, x * 2);
+		let triple = move |x: u32| ScriptedFuture::new(pending + 1, x * 3);
+		for (left, expected) in [(true, 2), (false, 3)].iter().copied() {
+			let mut projection = Box::pin(if left {
+				Either::Left(from_async_mut(double))
+			} else {
+				Either::Right(from_async_mut(triple))
+			});
+			assert_eq!(drive(projection.as_mut().project_fused(1)), expected);
+		}
+	}
+}
+
+#[test]
+fn fan_out_() {
+	for first in 0..=PENDING {
+		for second in 0..=PENDING {
+			let mut projection = Box::pin(fan_out((
+				ScriptedProjection::new(first, |x: &u32| x + 1),
+				ScriptedProjection::new(second, |x: &u32| x * 2),
+			)));
+			assert_eq!(drive(projection.as_mut().project_fused(&3)), (4, 6));
+			assert_eq!(block_on(projection.as_mut().project(&4)), (5, 8));
+		}
+	}
+}
+
+#[test]
+fn try_fan_out_() {
+	for first in 0..=PENDING {
+		for second in 0..=PENDING {
+			let mut projection = Box::pin(try_fan_out((
+				ScriptedProjection::new(first, |x: &u32| if *x > 0 { Ok(*x) } else { Err("zero") }),
+				ScriptedProjection::new(second, |x: &u32| Ok::<_, &str>(x * 2)),
+			)));
+			assert_eq!(drive(projection.as_mut().project_fused(&3)), Ok((3, 6)));
+			assert_eq!(drive(projection.as_mut().project_fused(&0)), Err("zero"));
+		}
+	}
+}
+
+#[test]
+fn select_() {
+	for left in 0..=PENDING {
+		for right in 0..=PENDING {
+			let mut projection = Box::pin(select(
+				ScriptedProjection::new(left, |x: &u32| x + 1),
+				ScriptedProjection::new(right, |x: &u32| *x > 0),
+			));
+			let expected = if left <= right {
+				Either::Left(2)
+			} else {
+				Either::Right(true)
+			};
+			assert_eq!(drive(projection.as_mut().project_fused(&1)), expected);
+		}
+	}
+}
+
+#[test]
+fn select_all_() {
+	let add = |pending: usize, n: u32| ScriptedProjection::new(pending, move |x: &u32| x + n);
+	for a in 0..=PENDING {
+		for b in 0..=PENDING {
+			for c in 0..=PENDING {
+				let mut projection = Box::pin(select_all([add(a, 0), add(b, 1), add(c, 2)]));
+				let pendings = [a, b, c];
+				let winner = (0..3).min_by_key(|&i| pendings[i]).unwrap();
+				assert_eq!(
+					drive(projection.as_mut().project_fused(&10)),
+					(winner, 10 + winner as u32)
+				);
+			}
+		}
+	}
+}
+
+#[test]
+fn memoize_() {
+	for pending in 0..=PENDING {
+		let mut projection = Box::pin(memoize(
+			from_async_mut(move |x: u32| ScriptedFuture::new(pending, x * 2)),
+			LruCache::<_, _, 2>::new(),
+		));
+		for x in [1, 2, 1, 3, 2].iter().copied() {
+			assert_eq!(drive(projection.as_mut().project_fused(x)), x * 2);
+		}
+	}
+}
+
+#[test]
+fn timeout_() {
+	for pending in 0..=PENDING {
+		let clock = ManualClock::<1>::new();
+		let mut projection = Box::pin(timeout(
+			from_async_mut(move |x: u32| ScriptedFuture::new(pending, x * 2)),
+			&clock,
+			Duration::from_secs(1),
+		));
+		assert_eq!(
+			drive_with_clock(
+				projection.as_mut().project_fused(1),
+				&clock,
+				Duration::from_secs(1)
+			),
+			Ok(2)
+		);
+		assert_eq!(clock.now(), Duration::from_secs(0));
+	}
+
+	let clock = ManualClock::<1>::new();
+	let mut projection = Box::pin(timeout(
+		from_async_mut(|()| pending::<()>()),
+		&clock,
+		Duration::from_secs(2),
+	));
+	assert_eq!(
+		drive_with_clock(
+			projection.as_mut().project_fused(()),
+			&clock,
+			Duration::from_secs(1)
+		),
+		Err(Elapsed)
+	);
+	assert_eq!(clock.now(), Duration::from_secs(2));
+}
+
+#[test]
+fn retry_() {
+	for pending in 0..=PENDING {
+		let clock = ManualClock::<1>::new();
+		let mut attempts = 0;
+		let mut projection = Box::pin(retry(
+			from_async_mut(move |threshold: usize| {
+				attempts += 1;
+				ScriptedFuture::new(
+					pending,
+					if attempts >= threshold {
+						Ok(attempts)
+					} else {
+						Err(attempts)
+					},
+				)
+			}),
+			FixedBackoff::new(Duration::from_secs(1), 3),
+			&clock,
+		));
+		assert_eq!(
+			drive_with_clock(
+				projection.as_mut().project_fused(3),
+				&clock,
+				Duration::from_secs(1)
+			),
+			Ok(3)
+		);
+		assert_eq!(clock.now(), Duration::from_secs(2));
+	}
+}
+
+// endregion
+// region: streams
+
+fn scripted(len: u32) -> ScriptedStream<u32> {
+	ScriptedStream::pending_before_each(0..len)
+}
+
+#[test]
+#[ergo_pin]
+fn peek_stream() {
+	for pending in 0..=PENDING {
+		let mut stream = pin!(PeekStream::<_, 3>::new(scripted(10)));
+		let two = NonZeroUsize::new(2).unwrap();
+		let three = NonZeroUsize::new(3).unwrap();
+
+		assert_eq!(block_on(stream.as_mut().peek_n(three)), Some(&2));
+		assert_eq!(
+			block_on(
+				stream
+					.as_mut()
+					.next_if(ScriptedProjection::new(pending, |x: &u32| *x == 0))
+			),
+			Some(0)
+		);
+		assert_eq!(
+			block_on(
+				stream
+					.as_mut()
+					.next_if_mut(ScriptedProjection::new(pending, |x: &u32| *x == 0))
+			),
+			None
+		);
+		assert_eq!(block_on(stream.as_mut().next_if_eq(&1)), Some(1));
+		assert_eq!(
+			block_on(
+				stream
+					.as_mut()
+					.peek_project(two, ScriptedProjection::new(pending, |x: &u32| x * 10))
+			),
+			Some(30)
+		);
+		assert_eq!(
+			block_on(
+				stream
+					.as_mut()
+					.peek_position(ScriptedProjection::new(pending, |x: &u32| *x == 4))
+			)
+			.map(NonZeroUsize::get),
+			Some(3)
+		);
+		assert_eq!(
+			block_on(
+				stream
+					.as_mut()
+					.next_if_map(ScriptedProjection::new(pending, |x: &u32| Some(x + 1)))
+			),
+			Some(3)
+		);
+		assert_eq!(
+			block_on(stream.as_mut().collect::<Vec<_>>()),
+			(3..10).collect::<Vec<_>>()
+		);
+		assert_eq!(block_on(stream.as_mut().peek_1()), None);
+	}
+}
+
+#[test]
+#[ergo_pin]
+fn peek1_stream() {
+	for pending in 0..=PENDING {
+		let mut stream = pin!(Peek1Stream::new(scripted(5)));
+		assert_eq!(block_on(stream.as_mut().peek()), Some(&0));
+		assert_eq!(
+			block_on(
+				stream
+					.as_mut()
+					.next_if(ScriptedProjection::new(pending, |x: &u32| *x == 0))
+			),
+			Some(0)
+		);
+		assert_eq!(
+			block_on(
+				stream
+					.as_mut()
+					.next_if_mut(ScriptedProjection::new(pending, |x: &u32| *x == 0))
+			),
+			None
+		);
+		assert_eq!(block_on(stream.as_mut().next_if_eq(&1)), Some(1));
+		assert_eq!(
+			block_on(
+				stream
+					.as_mut()
+					.next_if_map(ScriptedProjection::new(pending, |x: &u32| Some(x + 1)))
+			),
+			Some(3)
+		);
+		assert_eq!(block_on(stream.as_mut().collect::<Vec<_>>()), [3, 4]);
+		assert_eq!(block_on(stream.as_mut().peek()), None);
+	}
+}
+
+#[test]
+fn positioned() {
+	let items = block_on(Positioned::<_, ItemIndex>::new(scripted(3)).collect::<Vec<_>>());
+	assert_eq!(
+		items,
+		[(ItemIndex(0), 0), (ItemIndex(1), 1), (ItemIndex(2), 2)]
+	);
+}
+
+#[test]
+fn chunks_() {
+	let chunks = block_on(chunks::<_, 2>(scripted(5)).collect::<Vec<_>>());
+	assert_eq!(
+		chunks
+			.iter()
+			.map(|chunk| chunk.to_vec())
+			.collect::<Vec<_>>(),
+		[vec![0, 1], vec![2, 3], vec![4]]
+	);
+}
+
+#[test]
+#[ergo_pin]
+fn windows_() {
+	let mut windows = pin!(windows::<_, 3>(scripted(5)));
+	let mut sums = vec![];
+	while let Some(window) = block_on(windows.as_mut().next_window()) {
+		sums.push(window.iter().sum::<u32>());
+	}
+	assert_eq!(sums, [3, 6, 9]);
+}
+
+#[test]
+#[ergo_pin]
+fn broadcast() {
+	let broadcast = pin!(Broadcast::<_, 2, 2>::new(scripted(6)));
+	let broadcast = broadcast.into_ref();
+	let mut first = broadcast.cursor().unwrap();
+	let second = first.fork().unwrap();
+	assert_eq!(block_on(first.peek_1()), Some(0));
+	let (first, second) = block_on(join(first.collect::<Vec<_>>(), second.collect::<Vec<_>>()));
+	assert_eq!(first, (0..6).collect::<Vec<_>>());
+	assert_eq!(second, first);
+}
+
+#[test]
+#[ergo_pin]
+fn vec_broadcast() {
+	let broadcast = pin!(VecBroadcast::new(scripted(6), 2));
+	let broadcast = broadcast.into_ref();
+	let first = broadcast.cursor();
+	let second = first.fork().unwrap();
+	let (first, second) = block_on(join(first.collect::<Vec<_>>(), second.collect::<Vec<_>>()));
+	assert_eq!(first, (0..6).collect::<Vec<_>>());
+	assert_eq!(second, first);
+}
+
+#[test]
+fn project_buffered_() {
+	let double = |pending: usize| from_async_mut(move |x: u32| ScriptedFuture::new(pending, x * 2));
+	let doubled = project_buffered(scripted(8), [double(0), double(2), double(PENDING)]);
+	assert_eq!(
+		block_on(doubled.collect::<Vec<_>>()),
+		(0..8).map(|x| x * 2).collect::<Vec<_>>()
+	);
+
+	let unordered = project_buffer_unordered(scripted(8), [double(0), double(2), double(PENDING)]);
+	let mut unordered = block_on(unordered.collect::<Vec<_>>());
+	unordered.sort_unstable();
+	assert_eq!(unordered, (0..8).map(|x| x * 2).collect::<Vec<_>>());
+}
+
+#[test]
+#[ergo_pin]
+fn parse() {
+	let stream = pin!(PeekStream::<_, 8>::new(ScriptedStream::new(
+		"1,2,3;"
+			.chars()
+			.flat_map(|c| vec![Step::Pending, Step::Ready(c)])
+	)));
+	let (_, result) = block_on(separated_by(
+		Cursor::new(stream),
+		|cursor| token(cursor, "digit", ascii_digit()),
+		|cursor| token(cursor, "`,`", eq(',')),
+		0,
+		|sum, digit: char| sum + digit.to_digit(10).unwrap(),
+	));
+	assert_eq!(result, Ok(6));
+}
+
+// endregion